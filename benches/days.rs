@@ -0,0 +1,28 @@
+//! Times every registered day on its bundled input. Run with `cargo bench`,
+//! optionally followed by `-- <day>...` to bench only some days.
+
+use aoc_2022::bench::{bench_day, format_table, BenchOptions};
+use aoc_2022::input::InputSource;
+use aoc_2022::runner::registry;
+
+fn main() {
+    // cargo passes `--bench` along with any filters given after `--`.
+    let filters: Vec<u8> = std::env::args()
+        .skip(1)
+        .filter_map(|arg| arg.parse().ok())
+        .collect();
+
+    let source = InputSource::resolve(None, None);
+    let options = BenchOptions {
+        warmup: 10,
+        samples: 100,
+    };
+
+    let results: Vec<_> = registry()
+        .iter()
+        .filter(|day| filters.is_empty() || filters.contains(&day.number))
+        .map(|day| bench_day(day, &source, &options))
+        .collect();
+
+    print!("{}", format_table(&results));
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use crate::json;
+
+/// A solved part's answer, keeping its type so machine-readable output can
+/// tell numbers from text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rows of a picture, like the day 10 CRT.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Builds a grid answer from text with one row per line.
+    pub fn grid(text: &str) -> Self {
+        Self::Grid(text.lines().map(String::from).collect())
+    }
+
+    pub fn to_json(&self) -> String {
+        match self {
+            Self::Integer(n) => n.to_string(),
+            Self::Text(text) => json::string(text),
+            Self::Grid(rows) => {
+                let rows: Vec<String> = rows.iter().map(|row| json::string(row)).collect();
+                format!("[{}]", rows.join(", "))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Self::Integer(n.into())
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Self::Integer(n.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// What a part can return: anything that converts to an `Answer`, or a
+/// `Result` of one for parts that can fail on input that parsed fine.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, String>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, String> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: fmt::Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, String> {
+        self.map(Into::into).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_keeps_types() {
+        assert_eq!(Answer::from(42usize).to_json(), "42");
+        assert_eq!(Answer::from("CMZ".to_string()).to_json(), "\"CMZ\"");
+        assert_eq!(Answer::grid("#.\n.#").to_json(), "[\"#.\", \".#\"]");
+    }
+
+    #[test]
+    fn test_grid_displays_as_lines() {
+        assert_eq!(Answer::grid("#.\n.#").to_string(), "#.\n.#");
+    }
+}
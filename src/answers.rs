@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The checked-in answers file, used when no other file is given.
+pub const DEFAULT_ANSWERS_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// Known answers keyed by day, input name (see `InputSource::input_name`)
+/// and part.
+///
+/// Answers live in a small subset of TOML: one table per day and input,
+/// holding `part1` and `part2` as integers or (possibly multi-line)
+/// strings.
+///
+/// ```toml
+/// [day10."day10.txt"]
+/// part1 = 14360
+/// part2 = """
+/// ###...##..
+/// #..#.#..#."""
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, String, usize), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+        Self::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut result = Self::default();
+        let mut table: Option<(u8, String)> = None;
+        let mut lines = text.lines().enumerate();
+
+        while let Some((idx, raw)) = lines.next() {
+            let line = strip_comment(raw).trim();
+            let error = |reason: &str| format!("line {}: {reason}", idx + 1);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                table =
+                    Some(parse_header(header).ok_or_else(|| {
+                        error("expected a table header like [day1.\"day1.txt\"]")
+                    })?);
+                continue;
+            }
+
+            let (day, input) = table
+                .clone()
+                .ok_or_else(|| error("answers must come after a table header"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `part1 = ...` or `part2 = ...`"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(error("only part1 and part2 are allowed")),
+            };
+
+            let value = value.trim();
+            let answer = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                let mut body = rest.to_string();
+                while !body.contains("\"\"\"") {
+                    let (_, next) = lines
+                        .next()
+                        .ok_or_else(|| error("unterminated multi-line string"))?;
+                    body.push('\n');
+                    body.push_str(next);
+                }
+
+                let (body, _) = body.split_once("\"\"\"").unwrap();
+                let body = body.strip_prefix('\n').unwrap_or(body);
+                unescape(body).ok_or_else(|| error("invalid escape in string"))?
+            } else if let Some(rest) = value.strip_prefix('"') {
+                let body = rest
+                    .strip_suffix('"')
+                    .ok_or_else(|| error("unterminated string"))?;
+                unescape(body).ok_or_else(|| error("invalid escape in string"))?
+            } else {
+                value
+                    .replace('_', "")
+                    .parse::<i64>()
+                    .map_err(|_| error("expected an integer or a string"))?
+                    .to_string()
+            };
+
+            result.answers.insert((day, input, part), answer);
+        }
+
+        Ok(result)
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: usize) -> Option<&str> {
+        self.answers
+            .get(&(day, input.to_string(), part))
+            .map(String::as_str)
+    }
+}
+
+/// Parses `day7."day7.txt"` (or `day7.day7`) into the day and input name.
+fn parse_header(header: &str) -> Option<(u8, String)> {
+    let (day, input) = header.split_once('.')?;
+    let day = day.trim().strip_prefix("day")?.parse().ok()?;
+    let input = input.trim();
+    let input = match input.strip_prefix('"') {
+        Some(quoted) => quoted.strip_suffix('"')?,
+        None => input,
+    };
+
+    Some((day, input.to_string()))
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (idx, c) in line.char_indices() {
+        match c {
+            '\\' if in_string => escaped = !escaped,
+            '"' if !escaped => in_string = !in_string,
+            '#' if !in_string => return &line[..idx],
+            _ => escaped = false,
+        }
+    }
+
+    line
+}
+
+fn unescape(s: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            '"' => result.push('"'),
+            '\\' => result.push('\\'),
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+# Comments are ignored.
+[day1."day1.txt"]
+part1 = 75_501 # so are trailing ones
+part2 = "215594"
+
+[day10.day10_example]
+part2 = """
+##..
+#..#"""
+"#;
+
+    #[test]
+    fn test_parse_example() {
+        let answers = Answers::parse(EXAMPLE).unwrap();
+
+        assert_eq!(answers.get(1, "day1.txt", 1), Some("75501"));
+        assert_eq!(answers.get(1, "day1.txt", 2), Some("215594"));
+        assert_eq!(answers.get(10, "day10_example", 2), Some("##..\n#..#"));
+        assert_eq!(answers.get(10, "day10_example", 1), None);
+        assert_eq!(answers.get(2, "day1.txt", 1), None);
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let answers = Answers::parse("[day5.x]\npart1 = \"A\\\"#\\\\\"").unwrap();
+
+        assert_eq!(answers.get(5, "x", 1), Some("A\"#\\"));
+    }
+
+    #[test]
+    fn test_errors_name_the_line() {
+        assert_eq!(
+            Answers::parse("part1 = 3"),
+            Err("line 1: answers must come after a table header".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day1.a]\n\npart3 = 3"),
+            Err("line 3: only part1 and part2 are allowed".to_string())
+        );
+        assert!(Answers::parse("[day1.a]\npart1 = \"\"\"\nabc").is_err());
+        assert!(Answers::parse("[week1.a]").is_err());
+    }
+
+    #[test]
+    fn test_checked_in_answers_parse() {
+        let answers = Answers::load(Path::new(DEFAULT_ANSWERS_FILE)).unwrap();
+
+        (1..=10).for_each(|day| {
+            let input = format!("day{day}.txt");
+            assert!(answers.get(day, &input, 1).is_some(), "Day {day} part 1");
+            assert!(answers.get(day, &input, 2).is_some(), "Day {day} part 2");
+        });
+    }
+}
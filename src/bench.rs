@@ -0,0 +1,307 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::input::InputSource;
+use crate::json;
+use crate::runner::{Day, Outcome};
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts, to warm caches and the allocator.
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part(usize),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarises a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let mid = samples.len() / 2;
+        let median = if samples.len() % 2 == 1 {
+            samples[mid]
+        } else {
+            (samples[mid - 1] + samples[mid]) / 2
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Runs `f` `options.warmup` times untimed, then times `options.samples`
+/// runs of it (at least one).
+pub fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..options.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(samples)
+}
+
+/// Times parsing the input and each part on the parsed result. Parts are
+/// timed separately from parsing so a slow parser doesn't hide a slow part.
+pub fn bench_solution<S: Solution>(
+    input: &str,
+    options: &BenchOptions,
+) -> Result<Vec<Timing>, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(vec![
+        Timing {
+            stage: Stage::Parse,
+            stats: measure(options, || S::parse(black_box(input))),
+        },
+        Timing {
+            stage: Stage::Part(1),
+            stats: measure(options, || S::part_one(black_box(&parsed))),
+        },
+        Timing {
+            stage: Stage::Part(2),
+            stats: measure(options, || S::part_two(black_box(&parsed))),
+        },
+    ])
+}
+
+pub struct BenchResult {
+    pub day: u8,
+    /// The timings, or why the day couldn't be run.
+    pub timings: Result<Vec<Timing>, String>,
+}
+
+pub fn bench_day(day: &Day, source: &InputSource, options: &BenchOptions) -> BenchResult {
+    let timings = match source.load(day.number) {
+        Ok(input) => {
+            (day.bench)(&input, options).map_err(|err| Outcome::ParseError(err).to_string())
+        }
+        Err(err) => Err(Outcome::InputError(err.to_string()).to_string()),
+    };
+
+    BenchResult {
+        day: day.number,
+        timings,
+    }
+}
+
+pub fn format_table(results: &[BenchResult]) -> String {
+    let mut table = format!(
+        "Day  {:<6}  {:>10}  {:>10}  {:>10}\n",
+        "Stage", "Min", "Median", "Max"
+    );
+
+    for result in results {
+        match &result.timings {
+            Ok(timings) => {
+                for timing in timings {
+                    let stats = &timing.stats;
+                    table += &format!(
+                        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}\n",
+                        result.day,
+                        timing.stage.to_string(),
+                        format!("{:.1?}", stats.min),
+                        format!("{:.1?}", stats.median),
+                        format!("{:.1?}", stats.max)
+                    );
+                }
+            }
+            Err(err) => table += &format!("{:>3}  {err}\n", result.day),
+        }
+    }
+
+    table
+}
+
+/// Renders results as JSON, with times in nanoseconds, so runs from
+/// different commits can be saved and compared.
+pub fn to_json(results: &[BenchResult], options: &BenchOptions) -> String {
+    let entries: Vec<String> = results
+        .iter()
+        .flat_map(|result| match &result.timings {
+            Ok(timings) => timings
+                .iter()
+                .map(|timing| {
+                    format!(
+                        "    {{\"day\": {}, \"stage\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                        result.day,
+                        json::string(&timing.stage.to_string()),
+                        timing.stats.min.as_nanos(),
+                        timing.stats.median.as_nanos(),
+                        timing.stats.max.as_nanos()
+                    )
+                })
+                .collect(),
+            Err(err) => vec![format!(
+                "    {{\"day\": {}, \"error\": {}}}",
+                result.day,
+                json::string(err)
+            )],
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"warmup\": {},\n  \"samples\": {},\n  \"results\": [\n{}\n  ]\n}}\n",
+        options.warmup,
+        options.samples,
+        entries.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+
+        type Input = Vec<usize>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(idx, line)| {
+                    line.parse()
+                        .map_err(|_| ParseError::line(0, idx, line, "not a number"))
+                })
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Self::PartOne {
+            input.iter().sum()
+        }
+
+        fn part_two(input: &Self::Input) -> Self::PartTwo {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            Stats::from_samples(vec![ms(5), ms(1), ms(3)]),
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(9)]).median,
+            ms(3)
+        );
+    }
+
+    #[test]
+    fn test_measure_warms_up_then_samples() {
+        let options = BenchOptions {
+            warmup: 2,
+            samples: 5,
+        };
+        let mut calls = 0;
+        measure(&options, || calls += 1);
+
+        assert_eq!(calls, 7);
+    }
+
+    #[test]
+    fn test_bench_every_stage() {
+        let options = BenchOptions {
+            warmup: 0,
+            samples: 1,
+        };
+        let stages: Vec<Stage> = bench_solution::<Lines>("1\n2\n3", &options)
+            .unwrap()
+            .into_iter()
+            .map(|timing| timing.stage)
+            .collect();
+
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part(1), Stage::Part(2)]);
+        assert!(bench_solution::<Lines>("1\nx", &options).is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(1500)]);
+        let results = vec![
+            BenchResult {
+                day: 4,
+                timings: Ok(vec![Timing {
+                    stage: Stage::Part(2),
+                    stats,
+                }]),
+            },
+            BenchResult {
+                day: 5,
+                timings: Err("(could not read input: gone)".to_string()),
+            },
+        ];
+
+        let expected = r#"{
+  "warmup": 3,
+  "samples": 20,
+  "results": [
+    {"day": 4, "stage": "part 2", "min_ns": 1500, "median_ns": 1500, "max_ns": 1500},
+    {"day": 5, "error": "(could not read input: gone)"}
+  ]
+}
+"#;
+
+        assert_eq!(to_json(&results, &BenchOptions::default()), expected);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_2022::runner::print_day(1);
+}
@@ -0,0 +1,407 @@
+use aoc_2022::bench::BenchOptions;
+use aoc_2022::log::Filter;
+
+pub const USAGE: &str = "Usage:
+    aoc run [DAYS] [--part <1|2>] [--format <table|json>] [--input <FILE|->] [--input-dir <DIR>]
+    aoc verify [DAYS] [--answers <FILE>] [--input <FILE|->] [--input-dir <DIR>]
+    aoc bench [DAYS] [--warmup <N>] [--samples <N>] [--json <FILE>] [--input <FILE|->] [--input-dir <DIR>]
+    aoc explain [DAYS] [--input <FILE|->] [--input-dir <DIR>]
+    aoc list
+
+Any command also takes --quiet/-q to silence all logging, or -v / -vv for
+debug / trace logging from the solvers (more v's, as in -vvv, still mean
+trace). Without these, $RUST_LOG is used as a filter, e.g. `debug` or
+`warn,day7=trace`. Items for other targets, like `hyper=warn`, are ignored.
+Logs go to stderr.
+
+DAYS is a day number (7), a range (3-7 or 3..7), a comma separated list of
+either (1,4,6-8) or `all`. Defaults to all registered days.
+
+Inputs are read from --input (a single day only, `-` for stdin), otherwise
+dayN.txt in --input-dir, $AOC_INPUT_DIR or the bundled src/data directory.
+
+run prints a table by default. --format json prints one record per part
+instead: {day, part, answer, input, elapsed}, where answers are numbers,
+strings or (for pictures like day 10's) arrays of rows, and elapsed is the
+time in seconds including parsing.
+
+verify checks every answer against --answers, which defaults to the bundled
+answers.toml.
+
+bench times parsing and each part separately, reporting the min, median and
+max of --samples timed runs (default 20) after --warmup untimed ones (default
+3). --json also writes the timings to FILE so runs can be compared.
+
+explain prints a readable account of how the answers come out of the input,
+for the days that have one: for day 3, what's misplaced in each rucksack and
+which rucksacks can form badge groups in whatever order they're listed.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        days: DaySelection,
+        part: Option<usize>,
+        format: Format,
+        input: Option<String>,
+        input_dir: Option<String>,
+    },
+    Verify {
+        days: DaySelection,
+        input: Option<String>,
+        input_dir: Option<String>,
+        answers: Option<String>,
+    },
+    Bench {
+        days: DaySelection,
+        input: Option<String>,
+        input_dir: Option<String>,
+        options: BenchOptions,
+        json: Option<String>,
+    },
+    Explain {
+        days: DaySelection,
+        input: Option<String>,
+        input_dir: Option<String>,
+    },
+    List,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<u8>),
+}
+
+impl DaySelection {
+    pub fn is_single_day(&self) -> bool {
+        matches!(self, Self::Days(days) if days.len() == 1)
+    }
+
+    pub fn includes(&self, day: u8) -> bool {
+        match self {
+            Self::All => true,
+            Self::Days(days) => days.contains(&day),
+        }
+    }
+
+    fn parse(spec: &str) -> Result<Self, String> {
+        if spec == "all" {
+            return Ok(Self::All);
+        }
+
+        let mut days = Vec::new();
+        for item in spec.split(',') {
+            let range = item.split_once("..").or_else(|| item.split_once('-'));
+
+            match range {
+                Some((start, end)) => {
+                    let start = parse_day(start)?;
+                    let end = parse_day(end)?;
+                    if start > end {
+                        return Err(format!("Range {item} is backwards."));
+                    }
+                    days.extend(start..=end);
+                }
+                None => days.push(parse_day(item)?),
+            }
+        }
+
+        days.sort();
+        days.dedup();
+        Ok(Self::Days(days))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.trim()
+        .parse::<u8>()
+        .map_err(|_| format!("{s} is not a valid day number."))
+}
+
+fn parse_part(s: Option<&String>) -> Result<usize, String> {
+    match s.map(|s| s.as_str()) {
+        Some("1") => Ok(1),
+        Some("2") => Ok(2),
+        Some(other) => Err(format!("Part must be 1 or 2, got {other}.")),
+        None => Err("--part needs a value.".to_string()),
+    }
+}
+
+fn parse_format(s: Option<&String>) -> Result<Format, String> {
+    match s.map(|s| s.as_str()) {
+        Some("table") => Ok(Format::Table),
+        Some("json") => Ok(Format::Json),
+        Some(other) => Err(format!("Format must be table or json, got {other}.")),
+        None => Err("--format needs a value.".to_string()),
+    }
+}
+
+fn parse_count(flag: &str, s: Option<&String>) -> Result<usize, String> {
+    let value = parse_value(flag, s)?;
+    value
+        .parse()
+        .map_err(|_| format!("{flag} must be a whole number, got {value}."))
+}
+
+fn parse_value(flag: &str, s: Option<&String>) -> Result<String, String> {
+    s.cloned().ok_or_else(|| format!("{flag} needs a value."))
+}
+
+/// Pulls the logging flags out of the arguments, which are accepted
+/// anywhere. `-v` may be repeated within one flag, as in `-vvv`. Returns the filter they ask for, if any, and the remaining
+/// arguments.
+pub fn split_log_flags(args: &[String]) -> Result<(Option<Filter>, Vec<String>), String> {
+    let mut quiet = false;
+    let mut verbosity = 0;
+    let mut rest = Vec::new();
+
+    for arg in args {
+        match arg.as_str() {
+            "--quiet" | "-q" => quiet = true,
+            "--verbose" => verbosity += 1,
+            flag if is_verbose_flag(flag) => verbosity += flag.len() - 1,
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let filter = match (quiet, verbosity) {
+        (true, 0) => Some(Filter::new(None)),
+        (true, _) => return Err("--quiet and --verbose can't be used together.".to_string()),
+        (false, 0) => None,
+        (false, count) => Some(Filter::verbosity(count)),
+    };
+
+    Ok((filter, rest))
+}
+
+fn is_verbose_flag(arg: &str) -> bool {
+    arg.strip_prefix('-')
+        .is_some_and(|vs| !vs.is_empty() && vs.bytes().all(|b| b == b'v'))
+}
+
+/// Parses the arguments following the program name.
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(|s| s.as_str()) {
+        Some(command @ ("list" | "run" | "verify" | "bench" | "explain")) => command,
+        Some(other) => return Err(format!("Unknown command {other}.")),
+        None => return Err("No command given.".to_string()),
+    };
+
+    let mut days = DaySelection::All;
+    let mut part = None;
+    let mut format = Format::Table;
+    let mut input = None;
+    let mut input_dir = None;
+    let mut answers = None;
+    let mut options = BenchOptions::default();
+    let mut json = None;
+
+    while let Some(arg) = args.next() {
+        match (command, arg.as_str()) {
+            ("run", "--part" | "-p") => part = Some(parse_part(args.next())?),
+            ("run", "--format") => format = parse_format(args.next())?,
+            (_, "--input" | "-i") if command != "list" => {
+                input = Some(parse_value(arg, args.next())?)
+            }
+            (_, "--input-dir") if command != "list" => {
+                input_dir = Some(parse_value(arg, args.next())?)
+            }
+            ("verify", "--answers") => answers = Some(parse_value(arg, args.next())?),
+            ("bench", "--warmup") => options.warmup = parse_count(arg, args.next())?,
+            ("bench", "--samples") => options.samples = parse_count(arg, args.next())?,
+            ("bench", "--json") => json = Some(parse_value(arg, args.next())?),
+            (_, flag) if flag.starts_with('-') => {
+                return Err(format!("{flag} is not an option of {command}."))
+            }
+            ("run" | "verify" | "bench" | "explain", spec) => days = DaySelection::parse(spec)?,
+            (_, other) => return Err(format!("Unexpected argument {other}.")),
+        }
+    }
+
+    Ok(match command {
+        "run" => Command::Run {
+            days,
+            part,
+            format,
+            input,
+            input_dir,
+        },
+        "verify" => Command::Verify {
+            days,
+            input,
+            input_dir,
+            answers,
+        },
+        "bench" => Command::Bench {
+            days,
+            input,
+            input_dir,
+            options,
+            json,
+        },
+        "explain" => Command::Explain {
+            days,
+            input,
+            input_dir,
+        },
+        _ => Command::List,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_run_single_day_and_part() {
+        let expected = Command::Run {
+            days: DaySelection::Days(vec![7]),
+            part: Some(2),
+            format: Format::Table,
+            input: None,
+            input_dir: None,
+        };
+
+        assert_eq!(parse_args(&args("run 7 --part 2")), Ok(expected));
+    }
+
+    #[test]
+    fn test_run_defaults_to_everything() {
+        let expected = Command::Run {
+            days: DaySelection::All,
+            part: None,
+            format: Format::Table,
+            input: None,
+            input_dir: None,
+        };
+
+        assert_eq!(parse_args(&args("run")), Ok(expected));
+    }
+
+    #[test]
+    fn test_input_options() {
+        let expected = Command::Run {
+            days: DaySelection::Days(vec![6]),
+            part: None,
+            format: Format::Json,
+            input: Some("-".to_string()),
+            input_dir: Some("inputs".to_string()),
+        };
+
+        assert_eq!(
+            parse_args(&args("run 6 --input - --input-dir inputs --format json")),
+            Ok(expected)
+        );
+        assert!(parse_args(&args("run 6 --input")).is_err());
+    }
+
+    #[test]
+    fn test_day_ranges_and_lists() {
+        let expected = DaySelection::Days(vec![1, 3, 4, 5, 9]);
+
+        assert_eq!(DaySelection::parse("3-5,1,9"), Ok(expected));
+        assert_eq!(
+            DaySelection::parse("2..4"),
+            Ok(DaySelection::Days(vec![2, 3, 4]))
+        );
+    }
+
+    #[test]
+    fn test_verify_options() {
+        let expected = Command::Verify {
+            days: DaySelection::Days(vec![3, 4]),
+            input: None,
+            input_dir: None,
+            answers: Some("mine.toml".to_string()),
+        };
+
+        assert_eq!(
+            parse_args(&args("verify 3-4 --answers mine.toml")),
+            Ok(expected)
+        );
+        assert!(parse_args(&args("verify --part 1")).is_err());
+        assert!(parse_args(&args("run --answers mine.toml")).is_err());
+    }
+
+    #[test]
+    fn test_bench_options() {
+        let expected = Command::Bench {
+            days: DaySelection::Days(vec![8, 9]),
+            input: None,
+            input_dir: None,
+            options: BenchOptions {
+                warmup: 0,
+                samples: 5,
+            },
+            json: Some("bench.json".to_string()),
+        };
+
+        assert_eq!(
+            parse_args(&args("bench 8,9 --samples 5 --warmup 0 --json bench.json")),
+            Ok(expected)
+        );
+        assert!(parse_args(&args("bench --samples many")).is_err());
+        assert!(parse_args(&args("run --samples 5")).is_err());
+    }
+
+    #[test]
+    fn test_explain_options() {
+        let expected = Command::Explain {
+            days: DaySelection::Days(vec![3]),
+            input: Some("-".to_string()),
+            input_dir: None,
+        };
+
+        assert_eq!(parse_args(&args("explain 3 --input -")), Ok(expected));
+        assert!(parse_args(&args("explain 3 --part 1")).is_err());
+    }
+
+    #[test]
+    fn test_log_flags() {
+        let (filter, rest) = split_log_flags(&args("run -vv 7 --part 2")).unwrap();
+
+        assert_eq!(filter, Some(Filter::verbosity(2)));
+        assert_eq!(rest, args("run 7 --part 2"));
+        assert_eq!(
+            split_log_flags(&args("-q list")).unwrap().0,
+            Some(Filter::new(None))
+        );
+        assert_eq!(
+            split_log_flags(&args("run -v -v")).unwrap().0,
+            Some(Filter::verbosity(2))
+        );
+        assert_eq!(
+            split_log_flags(&args("run -vvv")).unwrap().0,
+            Some(Filter::verbosity(3))
+        );
+        assert_eq!(
+            split_log_flags(&args("run -vx")).unwrap().1,
+            args("run -vx")
+        );
+        assert_eq!(split_log_flags(&args("run")).unwrap().0, None);
+        assert!(split_log_flags(&args("run -q -v")).is_err());
+    }
+
+    #[test]
+    fn test_bad_arguments() {
+        assert!(parse_args(&args("run 5-3")).is_err());
+        assert!(parse_args(&args("run seven")).is_err());
+        assert!(parse_args(&args("run 1 --part 3")).is_err());
+        assert!(parse_args(&args("run --format xml")).is_err());
+        assert!(parse_args(&args("verify --format json")).is_err());
+        assert!(parse_args(&args("fly")).is_err());
+        assert!(parse_args(&args("list 3")).is_err());
+        assert!(parse_args(&[]).is_err());
+    }
+}
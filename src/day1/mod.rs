@@ -1,43 +1,357 @@
-use std::fs;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::num::IntErrorKind;
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error + 'static>> {
-    let raw_data = fs::read_to_string("src/day1/data.txt")?;
+use crate::error::ParseError;
+use crate::solution::Solution;
 
-    let result = find_top_calorie_elves(&raw_data, 1);
-    println!("The highest calorie elf has: {result} calories.");
-    let result_two = find_top_calorie_elves(&raw_data, 3);
-    println!("The top three elves together have: {result_two} calories");
+pub mod rebalance;
 
-    Ok(())
+/// How many elves part two adds up.
+pub const TOP_ELVES: usize = 3;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    /// The largest `TOP_ELVES` elf totals, largest first, found in a single
+    /// pass over the input without keeping every elf.
+    type Input = Vec<u64>;
+    type PartOne = Result<u64, CalorieError>;
+    type PartTwo = Result<u64, CalorieError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut top = TopN::new(TOP_ELVES);
+        for elf in elves(input.as_bytes()) {
+            match elf {
+                Ok(elf) => top.push(elf.total()),
+                Err(CalorieError::Parse(err)) => return Err(err),
+                Err(err) => panic!("Reading from a string can't fail: {err}"),
+            }
+        }
+
+        Ok(top.into_sorted_vec())
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        sum_largest(input, 1)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        sum_largest(input, TOP_ELVES)
+    }
 }
 
-fn find_top_calorie_elves(input_string: &str, number: usize) -> u32 {
-    let split_by_elf: Vec<&str> = input_string.split("\n\n").collect();
+/// One elf's inventory: the calories of each item they carry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Elf {
+    /// Where the elf comes in the input, counting from zero.
+    pub index: usize,
+    pub items: Vec<u64>,
+}
 
-    let mut data: Vec<u32> = split_by_elf
-        .iter()
-        .map(|elf_s| {
-            elf_s
-                .split_whitespace()
-                .map(|s| s.parse::<u32>().unwrap())
-                .sum::<u32>()
-        })
-        .collect();
-
-    data.sort();
-
-    data.as_slice()
-        .rchunks(number)
-        .next()
-        .unwrap()
+impl Elf {
+    /// The elf's total calories. Elves read by `elves` are checked not to
+    /// overflow.
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+}
+
+#[derive(Debug)]
+pub enum CalorieError {
+    Io(io::Error),
+    Parse(ParseError),
+    TooFewElves {
+        wanted: usize,
+        found: usize,
+    },
+    /// The top elves together carry more calories than fit in a `u64`.
+    Overflow,
+}
+
+impl fmt::Display for CalorieError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read input: {err}"),
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::TooFewElves { wanted, found } => {
+                write!(
+                    f,
+                    "wanted the top {wanted} elves, but there are only {found}"
+                )
+            }
+            Self::Overflow => write!(f, "the total calories are too large to add up"),
+        }
+    }
+}
+
+impl std::error::Error for CalorieError {}
+
+/// Reads elves one at a time. Each elf is a run of lines holding calorie
+/// counts, and elves are separated by blank lines (which may hold spaces or
+/// a `\r` from Windows line endings).
+pub struct Elves<R> {
+    lines: io::Lines<R>,
+    line_idx: usize,
+    elf_idx: usize,
+}
+
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves {
+        lines: reader.lines(),
+        line_idx: 0,
+        elf_idx: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, CalorieError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = Vec::new();
+        let mut total: u64 = 0;
+
+        for line in self.lines.by_ref() {
+            let idx = self.line_idx;
+            self.line_idx += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(CalorieError::Io(err))),
+            };
+            if line.trim().is_empty() {
+                if !items.is_empty() {
+                    break;
+                }
+                continue;
+            }
+
+            for s in line.split_whitespace() {
+                let calories = s
+                    .parse::<u64>()
+                    .map_err(|err| {
+                        let reason = match err.kind() {
+                            IntErrorKind::PosOverflow => "calorie count is too large",
+                            _ => "expected a calorie count",
+                        };
+                        ParseError::at(Day1::DAY, idx, &line, s, reason)
+                    })
+                    .and_then(|calories| {
+                        total = total.checked_add(calories).ok_or_else(|| {
+                            ParseError::at(
+                                Day1::DAY,
+                                idx,
+                                &line,
+                                s,
+                                "elf's total calories overflow",
+                            )
+                        })?;
+                        Ok(calories)
+                    });
+                match calories {
+                    Ok(calories) => items.push(calories),
+                    Err(err) => return Some(Err(CalorieError::Parse(err))),
+                }
+            }
+        }
+
+        if items.is_empty() {
+            return None;
+        }
+
+        let elf = Elf {
+            index: self.elf_idx,
+            items,
+        };
+        self.elf_idx += 1;
+        Some(Ok(elf))
+    }
+}
+
+/// Keeps the `n` largest values pushed so far in a min-heap, so memory
+/// stays bounded by `n` however many values go through it.
+pub struct TopN<T> {
+    n: usize,
+    heap: BinaryHeap<Reverse<T>>,
+    seen: usize,
+}
+
+impl<T: Ord> TopN<T> {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::with_capacity(n + 1),
+            seen: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.seen += 1;
+        self.heap.push(Reverse(value));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    /// How many values have been pushed.
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// The largest values, largest first. There are fewer than `n` if fewer
+    /// were pushed.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+
+    /// The `n` largest values, largest first, or `None` if fewer than `n`
+    /// were pushed.
+    pub fn finish(self) -> Option<Vec<T>> {
+        (self.seen >= self.n).then(|| self.into_sorted_vec())
+    }
+}
+
+fn finish_top_elves<T: Ord>(top: TopN<T>, wanted: usize) -> Result<Vec<T>, CalorieError> {
+    let found = top.seen();
+    top.finish()
+        .ok_or(CalorieError::TooFewElves { wanted, found })
+}
+
+/// The `number` elves carrying the most calories, most first. Ties go to
+/// the elf that comes first in `elves`.
+pub fn top_elves(elves: &[Elf], number: usize) -> Result<Vec<&Elf>, CalorieError> {
+    let mut top = TopN::new(number);
+    elves
         .iter()
-        .sum::<u32>()
+        .enumerate()
+        .for_each(|(position, elf)| top.push((elf.total(), Reverse(position))));
+
+    Ok(finish_top_elves(top, number)?
+        .into_iter()
+        .map(|(_, Reverse(position))| &elves[position])
+        .collect())
+}
+
+fn checked_sum(totals: impl IntoIterator<Item = u64>) -> Result<u64, CalorieError> {
+    totals.into_iter().try_fold(0u64, |sum, total| {
+        sum.checked_add(total).ok_or(CalorieError::Overflow)
+    })
+}
+
+/// Sums the calories carried by the `number` elves carrying the most.
+pub fn find_top_calorie_elves(elves: &[Elf], number: usize) -> Result<u64, CalorieError> {
+    checked_sum(top_elves(elves, number)?.iter().map(|elf| elf.total()))
+}
+
+/// Like `find_top_calorie_elves`, but reads the elves from `reader` one at
+/// a time without holding them all in memory.
+pub fn sum_top_calories<R: BufRead>(reader: R, number: usize) -> Result<u64, CalorieError> {
+    let mut top = TopN::new(number);
+    for elf in elves(reader) {
+        top.push(elf?.total());
+    }
+
+    checked_sum(finish_top_elves(top, number)?)
+}
+
+/// Sums the largest `number` of `totals`, which are sorted largest first
+/// and hold every elf if there are fewer than `number`.
+fn sum_largest(totals: &[u64], number: usize) -> Result<u64, CalorieError> {
+    match totals.get(..number) {
+        Some(top) => checked_sum(top.iter().copied()),
+        None => Err(CalorieError::TooFewElves {
+            wanted: number,
+            found: totals.len(),
+        }),
+    }
+}
+
+fn sorted_totals(elves: &[Elf]) -> Vec<u64> {
+    let mut totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+    totals.sort();
+    totals
+}
+
+pub fn mean_total(elves: &[Elf]) -> Option<f64> {
+    if elves.is_empty() {
+        return None;
+    }
+
+    let sum: u128 = elves.iter().map(|elf| u128::from(elf.total())).sum();
+    Some(sum as f64 / elves.len() as f64)
+}
+
+pub fn median_total(elves: &[Elf]) -> Option<f64> {
+    let totals = sorted_totals(elves);
+    let mid = totals.len() / 2;
+
+    match totals.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(totals[mid] as f64),
+        _ => Some((totals[mid - 1] as f64 + totals[mid] as f64) / 2.0),
+    }
+}
+
+/// The smallest total that at least `percent`% of elves carry no more
+/// than (the nearest-rank percentile). `percent` must be in `0..=100`.
+pub fn percentile_total(elves: &[Elf], percent: f64) -> Option<u64> {
+    assert!(
+        (0.0..=100.0).contains(&percent),
+        "Percentile must be between 0 and 100, got {percent}."
+    );
+
+    let totals = sorted_totals(elves);
+    let rank = ((percent / 100.0) * totals.len() as f64).ceil() as usize;
+
+    totals.get(rank.saturating_sub(1)).copied()
+}
+
+/// Counts elves by total in buckets `bucket_width` calories wide, as
+/// `(bucket start, count)` from the lowest bucket to the highest. Empty
+/// buckets in between are included.
+pub fn histogram(elves: &[Elf], bucket_width: u64) -> Vec<(u64, usize)> {
+    assert!(bucket_width > 0, "Buckets must be at least 1 calorie wide.");
+
+    let totals = sorted_totals(elves);
+    let (Some(first), Some(last)) = (totals.first(), totals.last()) else {
+        return Vec::new();
+    };
+
+    let first_bucket = first / bucket_width;
+    let mut counts = vec![0; (last / bucket_width - first_bucket) as usize + 1];
+    for total in &totals {
+        counts[(total / bucket_width - first_bucket) as usize] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(idx, count)| ((first_bucket + idx as u64) * bucket_width, count))
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn parse(data: &str) -> Vec<Elf> {
+        elves(data.as_bytes())
+            .collect::<Result<Vec<Elf>, CalorieError>>()
+            .unwrap()
+    }
+
     #[test]
     fn test_given_example() {
         let data = "
@@ -57,8 +371,8 @@ mod tests {
             10000
             ";
 
-        const EXPECTED: u32 = 24000;
-        let result = find_top_calorie_elves(data, 1);
+        const EXPECTED: u64 = 24000;
+        let result = find_top_calorie_elves(&parse(data), 1).unwrap();
         assert_eq!(result, EXPECTED);
     }
 
@@ -85,8 +399,8 @@ mod tests {
             10000
             ";
 
-        const EXPECTED: u32 = 60000;
-        let result = find_top_calorie_elves(data, 1);
+        const EXPECTED: u64 = 60000;
+        let result = find_top_calorie_elves(&parse(data), 1).unwrap();
         assert_eq!(result, EXPECTED);
     }
 
@@ -109,8 +423,175 @@ mod tests {
             10000
             ";
 
-        const EXPECTED: u32 = 45000;
-        let result = find_top_calorie_elves(data, 3);
+        const EXPECTED: u64 = 45000;
+        let result = find_top_calorie_elves(&parse(data), 3).unwrap();
         assert_eq!(result, EXPECTED);
     }
+
+    #[test]
+    fn test_bad_calorie_count_is_located() {
+        let data = "1000\n2000\n\n3000\n40x0\n";
+        let err = Day1::parse(data).unwrap_err();
+
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.text, "40x0");
+    }
+
+    #[test]
+    fn test_streaming_matches_slice() {
+        let data = "1000\n2000\n\n4000\n\n\n5000\n6000\n\n7000\n";
+
+        assert_eq!(sum_top_calories(data.as_bytes(), 2).unwrap(), 18000);
+        assert_eq!(
+            elves(data.as_bytes())
+                .map(|elf| elf.unwrap().total())
+                .collect::<Vec<u64>>(),
+            vec![3000, 4000, 11000, 7000]
+        );
+    }
+
+    #[test]
+    fn test_top_n_keeps_largest() {
+        let mut top = TopN::new(3);
+        [5, 1, 9, 3, 7, 2].into_iter().for_each(|v| top.push(v));
+
+        assert_eq!(top.finish().unwrap(), vec![9, 7, 5]);
+
+        let mut top = TopN::new(3);
+        [5, 1].into_iter().for_each(|v| top.push(v));
+        assert_eq!(top.seen(), 2);
+        assert_eq!(top.into_sorted_vec(), vec![5, 1]);
+        assert_eq!(TopN::<u8>::new(1).finish(), None);
+    }
+
+    #[test]
+    fn test_too_few_elves() {
+        let err = sum_top_calories("100\n\n200\n".as_bytes(), 3).unwrap_err();
+
+        assert!(matches!(
+            err,
+            CalorieError::TooFewElves {
+                wanted: 3,
+                found: 2
+            }
+        ));
+
+        // Part one only needs one elf, so only part two fails.
+        let elves = Day1::parse("100\n\n200\n").unwrap();
+        assert_eq!(Day1::part_one(&elves).unwrap(), 200);
+        assert_eq!(
+            Day1::part_two(&elves).unwrap_err().to_string(),
+            "wanted the top 3 elves, but there are only 2"
+        );
+    }
+
+    const INVENTORY: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn test_top_elves_know_who_they_are() {
+        let elves = parse(INVENTORY);
+        let top: Vec<(usize, u64, usize)> = top_elves(&elves, 3)
+            .unwrap()
+            .into_iter()
+            .map(|elf| (elf.index, elf.total(), elf.item_count()))
+            .collect();
+
+        assert_eq!(top, vec![(3, 24000, 3), (2, 11000, 2), (4, 10000, 1)]);
+    }
+
+    #[test]
+    fn test_parse_keeps_only_the_top_totals() {
+        assert_eq!(Day1::parse(INVENTORY).unwrap(), vec![24000, 11000, 10000]);
+        assert_eq!(Day1::parse("100\n\n200\n").unwrap(), vec![200, 100]);
+    }
+
+    #[test]
+    fn test_top_elves_of_a_subslice() {
+        let elves = parse(INVENTORY);
+        let top: Vec<usize> = top_elves(&elves[3..], 2)
+            .unwrap()
+            .into_iter()
+            .map(|elf| elf.index)
+            .collect();
+
+        assert_eq!(top, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let elves = parse(INVENTORY);
+
+        assert_eq!(mean_total(&elves), Some(11000.0));
+        assert_eq!(median_total(&elves), Some(10000.0));
+        assert_eq!(median_total(&elves[..4]), Some(8500.0));
+        assert_eq!(percentile_total(&elves, 50.0), Some(10000));
+        assert_eq!(percentile_total(&elves, 100.0), Some(24000));
+        assert_eq!(percentile_total(&elves, 0.0), Some(4000));
+        assert_eq!(mean_total(&[]), None);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let elves = parse(INVENTORY);
+
+        assert_eq!(
+            histogram(&elves, 10000),
+            vec![(0, 2), (10000, 2), (20000, 1)]
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings_and_padded_blank_lines() {
+        let data = "1000\r\n2000\r\n\r\n3000\r\n   \r\n4000\n \t\n5000\n";
+        let totals: Vec<u64> = parse(data).iter().map(Elf::total).collect();
+
+        assert_eq!(totals, vec![3000, 3000, 4000, 5000]);
+    }
+
+    #[test]
+    fn test_totals_beyond_u32() {
+        let data = "4000000000\n4000000000\n\n1\n\n2\n";
+
+        assert_eq!(
+            Day1::part_one(&Day1::parse(data).unwrap()).unwrap(),
+            8_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_totals_beyond_i64() {
+        let elves = Day1::parse("10000000000000000000\n\n10000000000000000000\n\n1\n").unwrap();
+
+        assert_eq!(Day1::part_one(&elves).unwrap(), 10_000_000_000_000_000_000);
+        assert!(matches!(
+            Day1::part_two(&elves),
+            Err(CalorieError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let data = "1\n\n2\n\n18446744073709551615\n1\n";
+        let err = Day1::parse(data).unwrap_err();
+
+        assert_eq!((err.line, err.column), (6, 1));
+        assert_eq!(err.reason, "elf's total calories overflow");
+
+        let err = Day1::parse("1\n\n2\n\n99999999999999999999\n").unwrap_err();
+        assert_eq!(err.reason, "calorie count is too large");
+
+        let data = "18446744073709551615\n\n1\n";
+        assert!(matches!(
+            sum_top_calories(data.as_bytes(), 2),
+            Err(CalorieError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_bad_number_on_crlf_line_is_located() {
+        let err = Day1::parse("1000\r\n\r\n2000\r\n3x00\r\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.text, "3x00");
+    }
 }
@@ -0,0 +1,321 @@
+//! Redistributing snacks so the most heavily loaded elf carries as little as
+//! possible: multiway number partitioning over every item in the inventory.
+
+use std::cmp::Reverse;
+
+use super::{checked_sum, CalorieError, Elf};
+
+/// Inputs with at most this many items are solved exactly. Beyond it the
+/// search can take too long, so the LPT heuristic is used instead.
+pub const EXACT_ITEM_LIMIT: usize = 16;
+
+/// How many partial assignments the exact search tries before giving up and
+/// settling for the best plan it has found.
+pub const EXACT_SEARCH_BUDGET: u64 = 10_000_000;
+
+/// A single snack and the elf who was carrying it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Item {
+    pub from: usize,
+    pub calories: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan {
+    /// The items each elf carries after rebalancing, by elf index.
+    pub loads: Vec<Vec<Item>>,
+    /// The heaviest load in the plan.
+    pub max_load: u64,
+    /// No plan can do better than this: the larger of the average load and
+    /// the heaviest single item.
+    pub lower_bound: u64,
+    /// Whether `max_load` is known to be the best possible.
+    pub exact: bool,
+}
+
+impl Plan {
+    pub fn totals(&self) -> Vec<u64> {
+        self.loads
+            .iter()
+            .map(|items| items.iter().map(|item| item.calories).sum())
+            .collect()
+    }
+
+    /// How far the plan could be from the best possible, as the ratio of
+    /// its heaviest load to the lower bound. 1.0 means it is optimal.
+    pub fn worst_case_ratio(&self) -> f64 {
+        if self.lower_bound == 0 {
+            1.0
+        } else {
+            self.max_load as f64 / self.lower_bound as f64
+        }
+    }
+}
+
+fn all_items(elves: &[Elf]) -> Vec<Item> {
+    elves
+        .iter()
+        .flat_map(|elf| {
+            elf.items.iter().map(|calories| Item {
+                from: elf.index,
+                calories: *calories,
+            })
+        })
+        .collect()
+}
+
+/// Sorts heaviest first, which both heuristics and the exact search rely on.
+fn sorted_items(elves: &[Elf]) -> Result<Vec<Item>, CalorieError> {
+    let mut items = all_items(elves);
+    checked_sum(items.iter().map(|item| item.calories))?;
+    items.sort_by_key(|item| Reverse(item.calories));
+
+    Ok(items)
+}
+
+fn lower_bound(items: &[Item], elves: usize) -> u64 {
+    let total: u64 = items.iter().map(|item| item.calories).sum();
+    let largest = items.iter().map(|item| item.calories).max().unwrap_or(0);
+
+    // Without elves there are no items either, and nothing to share out.
+    total.div_ceil(elves.max(1) as u64).max(largest)
+}
+
+fn plan(loads: Vec<Vec<Item>>, lower_bound: u64, exact: bool) -> Plan {
+    let max_load = loads
+        .iter()
+        .map(|items| items.iter().map(|item| item.calories).sum())
+        .max()
+        .unwrap_or(0);
+
+    Plan {
+        loads,
+        max_load,
+        lower_bound,
+        exact: exact || max_load == lower_bound,
+    }
+}
+
+/// Redistributes every item among the same number of elves, exactly when
+/// there are few enough items and with LPT otherwise.
+pub fn rebalance(elves: &[Elf]) -> Result<Plan, CalorieError> {
+    if all_items(elves).len() <= EXACT_ITEM_LIMIT {
+        rebalance_exact(elves)
+    } else {
+        rebalance_greedy(elves)
+    }
+}
+
+/// Longest processing time first: hands out items heaviest first, each to
+/// the elf carrying the least so far. The result is never more than
+/// `4/3 - 1/(3k)` times the optimum for `k` elves, and usually much closer;
+/// `Plan::worst_case_ratio` gives a bound for the particular input.
+pub fn rebalance_greedy(elves: &[Elf]) -> Result<Plan, CalorieError> {
+    let items = sorted_items(elves)?;
+    let mut loads: Vec<Vec<Item>> = vec![Vec::new(); elves.len()];
+    let mut totals = vec![0u64; elves.len()];
+
+    for item in &items {
+        let lightest = (0..totals.len())
+            .min_by_key(|idx| totals[*idx])
+            .expect("there is at least one elf when there are items");
+        totals[lightest] += item.calories;
+        loads[lightest].push(*item);
+    }
+
+    Ok(plan(loads, lower_bound(&items, elves.len()), false))
+}
+
+/// Finds the best possible redistribution with a branch and bound search,
+/// starting from the LPT plan. This is exponential in the number of items,
+/// so it stops after `EXACT_SEARCH_BUDGET` steps; the plan is then only
+/// marked exact if it meets the lower bound.
+pub fn rebalance_exact(elves: &[Elf]) -> Result<Plan, CalorieError> {
+    let greedy = rebalance_greedy(elves)?;
+    if greedy.exact {
+        return Ok(greedy);
+    }
+
+    let items = sorted_items(elves)?;
+    let mut remaining: Vec<u64> = items
+        .iter()
+        .rev()
+        .scan(0, |sum, item| {
+            *sum += item.calories;
+            Some(*sum)
+        })
+        .collect();
+    remaining.reverse();
+
+    let mut search = Search {
+        items: &items,
+        remaining,
+        lower_bound: greedy.lower_bound,
+        totals: vec![0; elves.len()],
+        assignment: vec![0; items.len()],
+        best: greedy.max_load,
+        best_assignment: None,
+        budget: EXACT_SEARCH_BUDGET,
+    };
+    search.place(0);
+    let finished = search.budget > 0;
+
+    let Some(assignment) = search.best_assignment else {
+        return Ok(Plan {
+            exact: finished,
+            ..greedy
+        });
+    };
+
+    let mut loads: Vec<Vec<Item>> = vec![Vec::new(); elves.len()];
+    for (item, elf) in items.iter().zip(assignment) {
+        loads[elf].push(*item);
+    }
+
+    Ok(plan(loads, greedy.lower_bound, finished))
+}
+
+struct Search<'a> {
+    items: &'a [Item],
+    /// The calories of `items[idx..]`, for each `idx`.
+    remaining: Vec<u64>,
+    lower_bound: u64,
+    totals: Vec<u64>,
+    assignment: Vec<usize>,
+    best: u64,
+    best_assignment: Option<Vec<usize>>,
+    budget: u64,
+}
+
+impl Search<'_> {
+    fn place(&mut self, idx: usize) {
+        if self.best == self.lower_bound || self.budget == 0 {
+            return;
+        }
+        self.budget -= 1;
+
+        if idx == self.items.len() {
+            let max_load = self.totals.iter().copied().max().unwrap_or(0);
+            if max_load < self.best {
+                self.best = max_load;
+                self.best_assignment = Some(self.assignment.clone());
+            }
+            return;
+        }
+
+        // Every elf can take up to one less than the best load so far. If
+        // the remaining items don't fit in that room, this branch can't win.
+        let room: u64 = self
+            .totals
+            .iter()
+            .map(|total| (self.best - 1).saturating_sub(*total))
+            .sum();
+        if room < self.remaining[idx] {
+            return;
+        }
+
+        let calories = self.items[idx].calories;
+        for elf in 0..self.totals.len() {
+            // Elves carrying the same load are interchangeable, so only try
+            // the first of them.
+            if self.totals[..elf].contains(&self.totals[elf]) {
+                continue;
+            }
+            if self.totals[elf] + calories >= self.best {
+                continue;
+            }
+
+            self.totals[elf] += calories;
+            self.assignment[idx] = elf;
+            self.place(idx + 1);
+            self.totals[elf] -= calories;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elves(inventories: &[&[u64]]) -> Vec<Elf> {
+        inventories
+            .iter()
+            .enumerate()
+            .map(|(index, items)| Elf {
+                index,
+                items: items.to_vec(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_greedy_is_not_always_optimal() {
+        // LPT puts 3+2+2 against 3+2, the best split is 3+3 and 2+2+2.
+        let example = elves(&[&[3, 3], &[2, 2, 2]]);
+
+        let greedy = rebalance_greedy(&example).unwrap();
+        assert_eq!(greedy.max_load, 7);
+        assert!(!greedy.exact);
+
+        let exact = rebalance_exact(&example).unwrap();
+        assert_eq!(exact.max_load, 6);
+        assert!(exact.exact);
+        assert_eq!(exact.totals(), vec![6, 6]);
+    }
+
+    #[test]
+    fn test_plan_keeps_every_item() {
+        let example = elves(&[&[1000, 2000, 3000], &[4000], &[5000, 6000]]);
+        let plan = rebalance(&example).unwrap();
+
+        let mut moved: Vec<(usize, u64)> = plan
+            .loads
+            .iter()
+            .flatten()
+            .map(|item| (item.from, item.calories))
+            .collect();
+        moved.sort();
+
+        assert_eq!(
+            moved,
+            vec![
+                (0, 1000),
+                (0, 2000),
+                (0, 3000),
+                (1, 4000),
+                (2, 5000),
+                (2, 6000)
+            ]
+        );
+        assert_eq!(plan.max_load, 7000);
+        assert_eq!(plan.lower_bound, 7000);
+    }
+
+    #[test]
+    fn test_large_inputs_use_greedy_with_a_bound() {
+        let items: Vec<u64> = (1..=40).collect();
+        let example = elves(&[&items[..], &[], &[]]);
+        let plan = rebalance(&example).unwrap();
+
+        assert_eq!(plan.lower_bound, 274);
+        assert!(plan.max_load >= plan.lower_bound);
+        assert!(plan.worst_case_ratio() <= 4.0 / 3.0);
+    }
+
+    #[test]
+    fn test_single_heavy_item_sets_the_bound() {
+        let plan = rebalance(&elves(&[&[100, 1], &[1]])).unwrap();
+
+        assert_eq!((plan.max_load, plan.lower_bound), (100, 100));
+        assert!(plan.exact);
+    }
+
+    #[test]
+    fn test_no_elves() {
+        let plan = rebalance(&[]).unwrap();
+
+        assert!(plan.loads.is_empty());
+        assert_eq!((plan.max_load, plan.lower_bound), (0, 0));
+        assert_eq!(rebalance_greedy(&[]).unwrap(), plan);
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::debug;
+use crate::error::{non_blank_lines, ParseError};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type PartOne = Result<i32, String>;
+    type PartTwo = Result<Answer, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_instructions_from_string(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        strengths_at_interval(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        Ok(Answer::grid(&draw_to_crt(input)?))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    NoOp,
+    AddX(i32),
+}
+
+pub fn get_instructions_from_string(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    non_blank_lines(input)
+        .map(|(idx, line)| {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words[..] {
+                ["noop"] => Ok(Instruction::NoOp),
+                ["addx", num] => num.parse::<i32>().map(Instruction::AddX).map_err(|_| {
+                    ParseError::at(Day10::DAY, idx, line, num, "expected a number to add")
+                }),
+                _ => Err(ParseError::line(
+                    Day10::DAY,
+                    idx,
+                    line,
+                    "expected `noop` or `addx <number>`",
+                )),
+            }
+        })
+        .collect()
+}
+
+fn add_to_register(register: i32, amount: i32, cycle: i32) -> Result<i32, String> {
+    register
+        .checked_add(amount)
+        .ok_or_else(|| format!("the X register overflows after cycle {cycle}"))
+}
+
+/// Calls `f` with each cycle and the X register during it. Fails if the
+/// register overflows.
+pub fn callback_in_cycles<F>(instructions: &[Instruction], mut f: F) -> Result<(), String>
+where
+    F: FnMut(i32, i32),
+{
+    let mut cycle = 0;
+    let mut register = 1;
+    for instruction in instructions {
+        match *instruction {
+            Instruction::NoOp => {
+                cycle += 1;
+
+                f(cycle, register);
+            }
+            Instruction::AddX(amount) => {
+                cycle += 1;
+                f(cycle, register);
+
+                cycle += 1;
+                f(cycle, register);
+
+                register = add_to_register(register, amount, cycle)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The sum of the signal strengths at cycle 20 and every 40 cycles after.
+/// Fails if the register or the sum overflows.
+pub fn strengths_at_interval(instructions: &[Instruction]) -> Result<i32, String> {
+    let mut result: i32 = 0;
+
+    let mut cycle = 0;
+    let mut register = 1;
+    let mut add_strength = |cycle: i32, register: i32, during: fmt::Arguments| {
+        if cycle == 20 || (cycle - 20) % 40 == 0 {
+            let overflow = || format!("the signal strengths overflow at cycle {cycle}");
+            let strength = cycle.checked_mul(register).ok_or_else(overflow)?;
+            debug!(
+                Day10::DAY,
+                "Strength at cycle {cycle} is {strength}, {during}"
+            );
+            result = result.checked_add(strength).ok_or_else(overflow)?;
+        }
+
+        Ok::<(), String>(())
+    };
+
+    for instruction in instructions {
+        match *instruction {
+            Instruction::NoOp => {
+                cycle += 1;
+                add_strength(cycle, register, format_args!("during a noop"))?;
+            }
+            Instruction::AddX(amount) => {
+                cycle += 1;
+                add_strength(
+                    cycle,
+                    register,
+                    format_args!("midway through addx {amount}"),
+                )?;
+
+                cycle += 1;
+                add_strength(cycle, register, format_args!("at the end of addx {amount}"))?;
+
+                register = add_to_register(register, amount, cycle)?;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+pub const CRT_WIDTH: usize = 40;
+
+/// Which pixels the CRT lights: one per cycle, drawn row by row. Fails if
+/// the register overflows.
+pub fn crt_pixels(instructions: &[Instruction]) -> Result<Grid<bool>, String> {
+    let mut pixels = Vec::new();
+    callback_in_cycles(instructions, |cycle, register| {
+        let line_pos = (cycle - 1) % CRT_WIDTH as i32;
+        pixels.push(line_pos.abs_diff(register) <= 1);
+    })?;
+
+    let mut screen = Grid::new(CRT_WIDTH, pixels.len().div_ceil(CRT_WIDTH), false);
+    for (idx, is_on) in pixels.into_iter().enumerate() {
+        screen[(idx % CRT_WIDTH, idx / CRT_WIDTH)] = is_on;
+    }
+
+    Ok(screen)
+}
+
+pub fn draw_to_crt(instructions: &[Instruction]) -> Result<String, String> {
+    Ok(crt_pixels(instructions)?.render(|is_on| if *is_on { '#' } else { '.' }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = std::fs::read_to_string("src/data/day10_example.txt").unwrap();
+        let actual = strengths_at_interval(&Day10::parse(&input).unwrap());
+        let expected = 13140;
+
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_alternative() {
+        let input = std::fs::read_to_string("src/data/day10_alt.txt").unwrap();
+        let actual = strengths_at_interval(&Day10::parse(&input).unwrap());
+        let expected = 81 * 20;
+
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_example_crt() {
+        let input = std::fs::read_to_string("src/data/day10_example.txt").unwrap();
+        let actual = draw_to_crt(&Day10::parse(&input).unwrap()).unwrap();
+        let expected = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let instructions = Day10::parse("addx 2147483647\naddx 1").unwrap();
+
+        assert_eq!(
+            Day10::part_one(&instructions),
+            Err("the X register overflows after cycle 2".to_string())
+        );
+        assert!(Day10::part_two(&instructions).is_err());
+
+        let instructions = Day10::parse("addx 200000000\n".repeat(10).as_str()).unwrap();
+        assert_eq!(
+            strengths_at_interval(&instructions),
+            Err("the signal strengths overflow at cycle 20".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bad_instruction_is_located() {
+        let err = Day10::parse("noop\naddx 3\naddx x\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 6));
+
+        let err = Day10::parse("noop\nmulx 3\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,505 @@
+//! Cyclic games like rock paper scissors with any odd number of shapes.
+
+use std::path::Path;
+
+use super::{single_char, Day2, Interpretation, Round, RoundResult};
+use crate::error::{non_blank_lines, ParseError};
+use crate::solution::Solution;
+use crate::trace;
+
+/// The day 2 rules, written in the same format `Game::parse` reads.
+pub const ROCK_PAPER_SCISSORS: &str = "
+# Shapes in cycle order: each one beats the (n - 1) / 2 shapes before it.
+# shape <name> <opponent symbol> <response symbol> <score>
+shape Rock A X 1
+shape Paper B Y 2
+shape Scissors C Z 3
+
+# The response symbol asking for each outcome, and what the outcome scores.
+lose X 0
+draw Y 3
+win Z 6
+";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shape {
+    pub name: String,
+    /// How the opponent's column of the guide writes this shape.
+    pub opponent: char,
+    /// How the second column writes this shape when read as a move.
+    pub response: char,
+    pub score: u32,
+}
+
+/// The symbol and score for one of the three round results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutcomeRule {
+    pub symbol: char,
+    pub score: u32,
+}
+
+/// A cyclic game: with the shapes in order, each one beats the half of the
+/// others that come just before it (wrapping around) and loses to the half
+/// just after it. That needs an odd number of shapes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    shapes: Vec<Shape>,
+    /// Lose, draw and win, in that order.
+    outcomes: [OutcomeRule; 3],
+}
+
+impl Game {
+    pub fn new(
+        shapes: Vec<Shape>,
+        lose: OutcomeRule,
+        draw: OutcomeRule,
+        win: OutcomeRule,
+    ) -> Result<Self, String> {
+        if shapes.len() < 3 || shapes.len() % 2 != 1 {
+            return Err(format!(
+                "a game needs an odd number of shapes, at least 3, found {}",
+                shapes.len()
+            ));
+        }
+
+        for (idx, shape) in shapes.iter().enumerate() {
+            for other in &shapes[..idx] {
+                if other.name == shape.name {
+                    return Err(format!("shape {} is defined twice", shape.name));
+                }
+                if other.opponent == shape.opponent {
+                    return Err(format!(
+                        "{} and {} both use opponent symbol {}",
+                        other.name, shape.name, shape.opponent
+                    ));
+                }
+                if other.response == shape.response {
+                    return Err(format!(
+                        "{} and {} both use response symbol {}",
+                        other.name, shape.name, shape.response
+                    ));
+                }
+            }
+        }
+
+        let outcomes = [lose, draw, win];
+        if (0..3).any(|i| outcomes[..i].iter().any(|o| o.symbol == outcomes[i].symbol)) {
+            return Err("each outcome needs its own symbol".to_string());
+        }
+
+        Ok(Self { shapes, outcomes })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::parse(ROCK_PAPER_SCISSORS).expect("the built-in game is valid")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+        Self::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    /// Parses a game definition like `ROCK_PAPER_SCISSORS`: a `shape` line
+    /// per shape in cycle order and a `lose`, `draw` and `win` line. `#`
+    /// starts a comment.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut shapes = Vec::new();
+        let mut outcomes: [Option<OutcomeRule>; 3] = [None; 3];
+
+        for (idx, raw) in text.lines().enumerate() {
+            let line = raw.split_once('#').map_or(raw, |(line, _)| line).trim();
+            let error = |reason: &str| format!("line {}: {reason}", idx + 1);
+            let symbol =
+                |s: &str| single_char(s).ok_or_else(|| error("symbols are a single character"));
+            let score = |s: &str| {
+                s.parse::<u32>()
+                    .map_err(|_| error("scores are non-negative integers"))
+            };
+
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words[..] {
+                [] => {}
+                ["shape", name, opponent, response, points] => shapes.push(Shape {
+                    name: name.to_string(),
+                    opponent: symbol(opponent)?,
+                    response: symbol(response)?,
+                    score: score(points)?,
+                }),
+                [result @ ("lose" | "draw" | "win"), sym, points] => {
+                    let slot = match result {
+                        "lose" => 0,
+                        "draw" => 1,
+                        _ => 2,
+                    };
+                    if outcomes[slot].is_some() {
+                        return Err(error(&format!("{result} is defined twice")));
+                    }
+                    outcomes[slot] = Some(OutcomeRule {
+                        symbol: symbol(sym)?,
+                        score: score(points)?,
+                    });
+                }
+                _ => {
+                    return Err(error(
+                        "expected `shape <name> <opponent> <response> <score>` or `<lose|draw|win> <symbol> <score>`",
+                    ))
+                }
+            }
+        }
+
+        let [Some(lose), Some(draw), Some(win)] = outcomes else {
+            return Err("a game needs lose, draw and win lines".to_string());
+        };
+
+        Self::new(shapes, lose, draw, win)
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn outcome(&self, result: RoundResult) -> OutcomeRule {
+        match result {
+            RoundResult::Lose => self.outcomes[0],
+            RoundResult::Draw => self.outcomes[1],
+            RoundResult::Win => self.outcomes[2],
+        }
+    }
+
+    /// How the shape at index `mine` fares against the one at `theirs`.
+    pub fn result(&self, mine: usize, theirs: usize) -> RoundResult {
+        let n = self.shapes.len();
+
+        match (mine + n - theirs) % n {
+            0 => RoundResult::Draw,
+            ahead if ahead <= n / 2 => RoundResult::Win,
+            _ => RoundResult::Lose,
+        }
+    }
+
+    /// The shape to play against `theirs` to get `result`. Bigger games have
+    /// several; the highest scoring one is picked.
+    pub fn shape_for_result(&self, theirs: usize, result: RoundResult) -> usize {
+        (0..self.shapes.len())
+            .filter(|mine| self.result(*mine, theirs) == result)
+            .max_by_key(|mine| (self.shapes[*mine].score, std::cmp::Reverse(*mine)))
+            .expect("every result is possible in a cyclic game")
+    }
+
+    pub fn shape_by_opponent(&self, symbol: char) -> Option<usize> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.opponent == symbol)
+    }
+
+    pub fn shape_by_response(&self, symbol: char) -> Option<usize> {
+        self.shapes
+            .iter()
+            .position(|shape| shape.response == symbol)
+    }
+
+    pub fn result_by_symbol(&self, symbol: char) -> Option<RoundResult> {
+        [RoundResult::Lose, RoundResult::Draw, RoundResult::Win]
+            .into_iter()
+            .find(|result| self.outcome(*result).symbol == symbol)
+    }
+
+    /// The shape to play and how the round turns out under `interpretation`,
+    /// or an error if the round doesn't fit this game read that way, like a
+    /// round parsed only for the other interpretation.
+    pub fn play(
+        &self,
+        round: &Round,
+        interpretation: Interpretation,
+    ) -> Result<(usize, RoundResult), String> {
+        if round.opponent >= self.shapes.len() {
+            return Err(format!(
+                "opponent shape {} is out of range, the game has {} shapes",
+                round.opponent,
+                self.shapes.len()
+            ));
+        }
+
+        match interpretation {
+            Interpretation::Move => {
+                let mine = self
+                    .shape_by_response(round.response)
+                    .ok_or_else(|| format!("`{}` isn't a move in this game", round.response))?;
+                Ok((mine, self.result(mine, round.opponent)))
+            }
+            Interpretation::Outcome => {
+                let result = self
+                    .result_by_symbol(round.response)
+                    .ok_or_else(|| format!("`{}` isn't an outcome in this game", round.response))?;
+                Ok((self.shape_for_result(round.opponent, result), result))
+            }
+        }
+    }
+
+    pub fn score_round(
+        &self,
+        round: &Round,
+        interpretation: Interpretation,
+    ) -> Result<u32, String> {
+        let (mine, result) = self.play(round, interpretation)?;
+
+        Ok(self.shapes[mine].score + self.outcome(result).score)
+    }
+
+    pub fn score(&self, rounds: &[Round], interpretation: Interpretation) -> Result<u32, String> {
+        rounds
+            .iter()
+            .map(|round| {
+                let (mine, result) = self.play(round, interpretation)?;
+                let total_score = self.shapes[mine].score + self.outcome(result).score;
+                trace!(
+                    Day2::DAY,
+                    "I played {} against {} for a {result:?} and a score of {total_score}",
+                    self.shapes[mine].name,
+                    self.shapes[round.opponent].name
+                );
+
+                Ok(total_score)
+            })
+            .sum()
+    }
+
+    pub fn score_moves(&self, rounds: &[Round]) -> Result<u32, String> {
+        self.score(rounds, Interpretation::Move)
+    }
+
+    pub fn score_strategy(&self, rounds: &[Round]) -> Result<u32, String> {
+        self.score(rounds, Interpretation::Outcome)
+    }
+
+    /// Parses a strategy guide written with this game's symbols. The second
+    /// column has to make sense under each of `interpretations`.
+    pub fn parse_rounds(
+        &self,
+        day: u8,
+        input: &str,
+        interpretations: &[Interpretation],
+    ) -> Result<Vec<Round>, ParseError> {
+        let mut opponents: Vec<char> = self.shapes.iter().map(|shape| shape.opponent).collect();
+        opponents.sort_unstable();
+        let mut responses: Vec<char> = self
+            .shapes
+            .iter()
+            .map(|shape| shape.response)
+            .chain(self.outcomes.iter().map(|outcome| outcome.symbol))
+            .filter(|c| {
+                interpretations
+                    .iter()
+                    .all(|interpretation| match interpretation {
+                        Interpretation::Move => self.shape_by_response(*c).is_some(),
+                        Interpretation::Outcome => self.result_by_symbol(*c).is_some(),
+                    })
+            })
+            .fold(Vec::new(), |mut symbols, c| {
+                if !symbols.contains(&c) {
+                    symbols.push(c);
+                }
+                symbols
+            });
+        responses.sort_unstable();
+
+        non_blank_lines(input)
+            .map(|(idx, line)| {
+                let columns: Vec<&str> = line.split_whitespace().collect();
+                let [opponent, response] = columns[..] else {
+                    return Err(ParseError::line(
+                        day,
+                        idx,
+                        line,
+                        format!(
+                            "expected two columns like `{} {}`",
+                            opponents[0],
+                            responses.first().unwrap_or(&'?')
+                        ),
+                    ));
+                };
+
+                let opponent_shape = single_char(opponent)
+                    .and_then(|c| self.shape_by_opponent(c))
+                    .ok_or_else(|| {
+                        ParseError::at(day, idx, line, opponent, expected(&opponents))
+                    })?;
+                let response_char = single_char(response)
+                    .filter(|c| responses.contains(c))
+                    .ok_or_else(|| {
+                        ParseError::at(day, idx, line, response, expected(&responses))
+                    })?;
+
+                Ok(Round {
+                    opponent: opponent_shape,
+                    response: response_char,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Lists symbols for an error message, like "expected A, B or C".
+fn expected(symbols: &[char]) -> String {
+    match symbols {
+        [] => "no symbol fits every interpretation".to_string(),
+        [only] => format!("expected {only}"),
+        [rest @ .., last] => {
+            let rest: Vec<String> = rest.iter().map(char::to_string).collect();
+            format!("expected {} or {last}", rest.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Report;
+    use super::*;
+
+    fn rpsls() -> Game {
+        Game::load(Path::new("src/data/day2_rpsls.game")).unwrap()
+    }
+
+    #[test]
+    fn test_built_in_game_rules() {
+        let game = Game::rock_paper_scissors();
+        let names: Vec<&str> = game
+            .shapes()
+            .iter()
+            .map(|shape| shape.name.as_str())
+            .collect();
+        let beats = [
+            ("Rock", "Scissors"),
+            ("Paper", "Rock"),
+            ("Scissors", "Paper"),
+        ];
+
+        assert_eq!(names, ["Rock", "Paper", "Scissors"]);
+        for mine in 0..3 {
+            for theirs in 0..3 {
+                let expected = if mine == theirs {
+                    RoundResult::Draw
+                } else if beats.contains(&(names[mine], names[theirs])) {
+                    RoundResult::Win
+                } else {
+                    RoundResult::Lose
+                };
+
+                assert_eq!(game.result(mine, theirs), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rpsls_rules() {
+        let game = rpsls();
+        let shape = |name: &str| {
+            game.shapes()
+                .iter()
+                .position(|shape| shape.name == name)
+                .unwrap()
+        };
+
+        assert_eq!(
+            game.result(shape("Spock"), shape("Scissors")),
+            RoundResult::Win
+        );
+        assert_eq!(
+            game.result(shape("Lizard"), shape("Spock")),
+            RoundResult::Win
+        );
+        assert_eq!(
+            game.result(shape("Rock"), shape("Lizard")),
+            RoundResult::Win
+        );
+        assert_eq!(
+            game.result(shape("Paper"), shape("Scissors")),
+            RoundResult::Lose
+        );
+        assert_eq!(
+            game.result(shape("Spock"), shape("Paper")),
+            RoundResult::Lose
+        );
+        assert_eq!(
+            game.result(shape("Lizard"), shape("Lizard")),
+            RoundResult::Draw
+        );
+    }
+
+    #[test]
+    fn test_score_strategy_over_rpsls() {
+        let game = rpsls();
+        let rounds = game
+            .parse_rounds(2, "A Z\nE X\nC V", &[Interpretation::Move])
+            .unwrap();
+
+        // Spock vaporizes Rock, Spock smashes Scissors, Rock crushes Scissors.
+        assert_eq!(game.score_moves(&rounds), Ok((5 + 6) + 3 + (1 + 6)));
+
+        let rounds = game
+            .parse_rounds(2, "A Z\nE X", &[Interpretation::Outcome])
+            .unwrap();
+        // Spock and Paper both beat Rock, and Spock scores more. Rock and
+        // Scissors both lose to Spock, and Scissors scores more.
+        assert_eq!(game.score_strategy(&rounds), Ok((5 + 6) + 3));
+    }
+
+    #[test]
+    fn test_round_read_the_other_way_is_an_error() {
+        let game = rpsls();
+        let rounds = game
+            .parse_rounds(2, "A V", &[Interpretation::Move])
+            .unwrap();
+
+        assert_eq!(
+            game.score_strategy(&rounds),
+            Err("`V` isn't an outcome in this game".to_string())
+        );
+        assert!(Report::new(&game, &rounds, Interpretation::Outcome).is_err());
+        assert_eq!(
+            Game::rock_paper_scissors().score_moves(&[Round {
+                opponent: 4,
+                response: 'X'
+            }]),
+            Err("opponent shape 4 is out of range, the game has 3 shapes".to_string())
+        );
+    }
+
+    #[test]
+    fn test_response_must_suit_every_interpretation() {
+        let game = rpsls();
+        let both = [Interpretation::Move, Interpretation::Outcome];
+        let err = game.parse_rounds(2, "A Y\nB V", &both).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.reason, "expected X, Y or Z");
+    }
+
+    #[test]
+    fn test_config_errors() {
+        let outcomes = "lose X 0\ndraw Y 3\nwin Z 6\n";
+
+        assert_eq!(
+            Game::parse(&format!("shape Rock A X 1\nshape Paper B Y 2\n{outcomes}")),
+            Err("a game needs an odd number of shapes, at least 3, found 2".to_string())
+        );
+        assert_eq!(
+            Game::parse("shape Rock A X 1\nshape Paper A Y 2\nshape Scissors C Z 3\nlose X 0\ndraw Y 3\nwin Z 6"),
+            Err("Rock and Paper both use opponent symbol A".to_string())
+        );
+        assert_eq!(
+            Game::parse("shape Rock A X one"),
+            Err("line 1: scores are non-negative integers".to_string())
+        );
+        assert_eq!(
+            Game::parse("shape Rock A X 1\nlose X"),
+            Err("line 2: expected `shape <name> <opponent> <response> <score>` or `<lose|draw|win> <symbol> <score>`".to_string())
+        );
+        assert_eq!(
+            Game::parse("shape Rock A X 1"),
+            Err("a game needs lose, draw and win lines".to_string())
+        );
+    }
+}
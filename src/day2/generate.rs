@@ -0,0 +1,290 @@
+//! Writing strategy guides that score a chosen total when read as outcomes,
+//! for known-answer test inputs.
+
+use super::{Game, RoundResult};
+
+/// A small xorshift generator, so guides can be reproduced from a seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Xorshift gets stuck on zero, and small seeds start out poorly
+        // mixed, so spread the seed over the state first.
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..n`, for `n > 0`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Writes a guide of `rounds` lines whose outcome reading scores exactly
+/// `target`. If `opponents` is given the first column follows it (and must
+/// have `rounds` entries); otherwise the opponent's moves are random too.
+pub fn generate_guide(
+    game: &Game,
+    target: u32,
+    rounds: usize,
+    opponents: Option<&[usize]>,
+    rng: &mut Rng,
+) -> Result<String, String> {
+    if opponents.is_some_and(|opponents| opponents.len() != rounds) {
+        return Err(format!("expected {rounds} opponent moves"));
+    }
+    if let Some(opponent) = opponents
+        .unwrap_or_default()
+        .iter()
+        .find(|opponent| **opponent >= game.shapes().len())
+    {
+        return Err(format!(
+            "opponent move {opponent} is out of range, the game has {} shapes",
+            game.shapes().len()
+        ));
+    }
+
+    let results = [RoundResult::Lose, RoundResult::Draw, RoundResult::Win];
+    let all_opponents: Vec<usize> = (0..game.shapes().len()).collect();
+    // The (opponent, result, points) choices for each round.
+    let choices: Vec<Vec<(usize, RoundResult, u32)>> = (0..rounds)
+        .map(|idx| {
+            let opponents = match opponents {
+                Some(opponents) => &opponents[idx..=idx],
+                None => &all_opponents[..],
+            };
+            opponents
+                .iter()
+                .flat_map(|opponent| {
+                    results.iter().map(|result| {
+                        let mine = game.shape_for_result(*opponent, *result);
+                        let points = game.shapes()[mine].score + game.outcome(*result).score;
+                        (*opponent, *result, points)
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    let reachable = Reachable::new(&choices, u64::from(target));
+    if !reachable.contains(0, u64::from(target)) {
+        return Err(format!("no {rounds}-round guide scores exactly {target}"));
+    }
+
+    let mut guide = String::new();
+    let mut remaining = u64::from(target);
+    for (idx, options) in choices.iter().enumerate() {
+        let fitting: Vec<_> = options
+            .iter()
+            .filter(|(_, _, points)| {
+                remaining
+                    .checked_sub(u64::from(*points))
+                    .is_some_and(|rest| reachable.contains(idx + 1, rest))
+            })
+            .collect();
+        let (opponent, result, points) = fitting[rng.below(fitting.len())];
+
+        remaining -= u64::from(*points);
+        guide += &format!(
+            "{} {}\n",
+            game.shapes()[*opponent].opponent,
+            game.outcome(*result).symbol
+        );
+    }
+
+    Ok(guide)
+}
+
+/// Which totals the rounds from each index on can add up to.
+///
+/// A round whose possible points have no gaps (1 to 9 when the opponent is
+/// free) adds a range of totals to a range, so while every round is like
+/// that each suffix is described by its lowest and highest total. Rounds
+/// with gaps (against a fixed opponent) also need a bit per total, kept
+/// only between the suffix's lowest total and the target.
+struct Reachable {
+    min: Vec<u64>,
+    max: Vec<u64>,
+    bits: Option<Vec<Vec<u64>>>,
+}
+
+impl Reachable {
+    fn new(choices: &[Vec<(usize, RoundResult, u32)>], target: u64) -> Self {
+        let rounds = choices.len();
+        let points: Vec<Vec<u64>> = choices
+            .iter()
+            .map(|options| {
+                let mut points: Vec<u64> = options
+                    .iter()
+                    .map(|(_, _, points)| u64::from(*points))
+                    .collect();
+                points.sort();
+                points.dedup();
+                points
+            })
+            .collect();
+
+        let mut min = vec![0; rounds + 1];
+        let mut max = vec![0; rounds + 1];
+        for idx in (0..rounds).rev() {
+            min[idx] = min[idx + 1] + points[idx].first().copied().unwrap_or(0);
+            max[idx] = max[idx + 1] + points[idx].last().copied().unwrap_or(0);
+        }
+
+        let has_gaps = points
+            .iter()
+            .any(|points| match (points.first(), points.last()) {
+                (Some(first), Some(last)) => last - first + 1 != points.len() as u64,
+                _ => false,
+            });
+        let mut reachable = Self {
+            min,
+            max,
+            bits: None,
+        };
+        if has_gaps && reachable.min[0] <= target {
+            reachable.bits = Some(reachable.bits(&points, target));
+        }
+
+        reachable
+    }
+
+    /// One row of bits per suffix, bit `i` standing for the total
+    /// `min[idx] + i`.
+    fn bits(&self, points: &[Vec<u64>], target: u64) -> Vec<Vec<u64>> {
+        let rounds = points.len();
+        let width = |idx: usize| self.max[idx].min(target).saturating_sub(self.min[idx]) + 1;
+        let mut rows: Vec<Vec<u64>> = vec![Vec::new(); rounds + 1];
+        rows[rounds] = vec![1];
+
+        for idx in (0..rounds).rev() {
+            if self.min[idx] > target {
+                continue;
+            }
+            let mut row = vec![0u64; width(idx).div_ceil(64) as usize];
+            let next = &rows[idx + 1];
+            for offset in 0..width(idx) {
+                let total = self.min[idx] + offset;
+                let fits = points[idx].iter().any(|points| {
+                    total
+                        .checked_sub(*points)
+                        .and_then(|rest| rest.checked_sub(self.min[idx + 1]))
+                        .is_some_and(|bit| bit < width(idx + 1) && get_bit(next, bit))
+                });
+                if fits {
+                    row[(offset / 64) as usize] |= 1 << (offset % 64);
+                }
+            }
+            rows[idx] = row;
+        }
+
+        rows
+    }
+
+    /// Whether the rounds from `idx` on can add up to exactly `total`.
+    fn contains(&self, idx: usize, total: u64) -> bool {
+        if !(self.min[idx]..=self.max[idx]).contains(&total) {
+            return false;
+        }
+
+        self.bits
+            .as_ref()
+            .is_none_or(|rows| get_bit(&rows[idx], total - self.min[idx]))
+    }
+}
+
+fn get_bit(row: &[u64], bit: u64) -> bool {
+    row.get((bit / 64) as usize)
+        .is_some_and(|word| word >> (bit % 64) & 1 == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{score, Day2, Interpretation};
+    use super::*;
+    use crate::solution::Solution;
+
+    #[test]
+    fn test_generated_guides_hit_the_target() {
+        let game = Game::rock_paper_scissors();
+
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let target = 10 + rng.below(60) as u32;
+            let guide = generate_guide(&game, target, 10, None, &mut rng).unwrap();
+            let rounds = Day2::parse(&guide).unwrap();
+
+            assert_eq!(rounds.len(), 10);
+            assert_eq!(score(&rounds, Interpretation::Outcome), Ok(target));
+        }
+    }
+
+    #[test]
+    fn test_fixed_opponents_are_kept() {
+        let game = Game::rock_paper_scissors();
+        let opponents = [0, 1, 2];
+        let guide = generate_guide(&game, 24, 3, Some(&opponents), &mut Rng::new(7)).unwrap();
+
+        // Winning every round is the only way to 24.
+        assert_eq!(guide, "A Z\nB Z\nC Z\n");
+    }
+
+    #[test]
+    fn test_impossible_targets() {
+        let game = Game::rock_paper_scissors();
+        let mut rng = Rng::new(1);
+
+        // A round scores at least 1 and at most 9.
+        assert!(generate_guide(&game, 0, 1, None, &mut rng).is_err());
+        assert!(generate_guide(&game, 28, 3, None, &mut rng).is_err());
+        // Against rock a round scores 3, 4 or 8.
+        assert_eq!(
+            generate_guide(&game, 5, 1, Some(&[0]), &mut rng),
+            Err("no 1-round guide scores exactly 5".to_string())
+        );
+        assert!(generate_guide(&game, 5, 2, Some(&[0]), &mut rng).is_err());
+        assert_eq!(
+            generate_guide(&game, 0, 0, None, &mut rng),
+            Ok(String::new())
+        );
+        assert_eq!(
+            generate_guide(&game, 3_000_000_000, 10, None, &mut rng),
+            Err("no 10-round guide scores exactly 3000000000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_many_rounds() {
+        let game = Game::rock_paper_scissors();
+        let mut rng = Rng::new(3);
+
+        let guide = generate_guide(&game, 100_000, 20_000, None, &mut rng).unwrap();
+        let rounds = Day2::parse(&guide).unwrap();
+        assert_eq!(rounds.len(), 20_000);
+        assert_eq!(score(&rounds, Interpretation::Outcome), Ok(100_000));
+
+        let opponents: Vec<usize> = (0..2_000).map(|_| rng.below(3)).collect();
+        let guide = generate_guide(&game, 10_000, 2_000, Some(&opponents), &mut rng).unwrap();
+        assert_eq!(
+            score(&Day2::parse(&guide).unwrap(), Interpretation::Outcome),
+            Ok(10_000)
+        );
+    }
+
+    #[test]
+    fn test_unknown_opponent_moves() {
+        let game = Game::rock_paper_scissors();
+
+        assert_eq!(
+            generate_guide(&game, 8, 1, Some(&[3]), &mut Rng::new(1)),
+            Err("opponent move 3 is out of range, the game has 3 shapes".to_string())
+        );
+    }
+}
@@ -0,0 +1,164 @@
+pub mod game;
+pub mod generate;
+pub mod report;
+pub mod strategy;
+
+pub use game::Game;
+pub use report::Report;
+
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum RoundResult {
+    Win,
+    Lose,
+    Draw,
+}
+
+/// What the X, Y and Z in the second column of the strategy guide mean.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Interpretation {
+    /// X, Y and Z are the move to play: rock, paper and scissors. Part one.
+    Move,
+    /// X, Y and Z are the result to aim for: lose, draw and win. Part two.
+    Outcome,
+}
+
+/// One line of the strategy guide. What the second column means depends on
+/// the `Interpretation`, so it is kept as the raw char; `Game::play` works
+/// out the rest.
+#[derive(Debug)]
+pub struct Round {
+    /// The index of the opponent's shape in the game.
+    pub opponent: usize,
+    pub response: char,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+    type PartOne = Result<u32, String>;
+    type PartTwo = Result<u32, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Game::rock_paper_scissors().parse_rounds(
+            Self::DAY,
+            input,
+            &[Interpretation::Move, Interpretation::Outcome],
+        )
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        score(input, Interpretation::Move)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        score(input, Interpretation::Outcome)
+    }
+}
+
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    let c = chars.next()?;
+
+    chars.next().is_none().then_some(c)
+}
+
+/// The total score from following the guide under `interpretation`.
+pub fn score(rounds: &[Round], interpretation: Interpretation) -> Result<u32, String> {
+    Game::rock_paper_scissors().score(rounds, interpretation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_strat() {
+        const DATA: &str = "A Y
+                            B X
+                            C Z";
+        const EXPECTED: u32 = 12;
+        let actual = score(&Day2::parse(DATA).unwrap(), Interpretation::Outcome).unwrap();
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_example_moves() {
+        const DATA: &str = "A Y
+                            B X
+                            C Z";
+        const EXPECTED: u32 = 15;
+        let actual = score(&Day2::parse(DATA).unwrap(), Interpretation::Move).unwrap();
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_interpretations() {
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+
+        assert_eq!(score(&rounds, Interpretation::Move), Ok(15));
+        assert_eq!(score(&rounds, Interpretation::Outcome), Ok(12));
+        assert_eq!(Day2::part_one(&rounds), Ok(15));
+        assert_eq!(Day2::part_two(&rounds), Ok(12));
+    }
+
+    #[test]
+    fn test_round_under_each_interpretation() {
+        let game = Game::rock_paper_scissors();
+        let round = Round {
+            opponent: 1,
+            response: 'X',
+        };
+
+        assert_eq!(
+            game.play(&round, Interpretation::Move).unwrap().1,
+            RoundResult::Lose
+        );
+        assert_eq!(game.score_round(&round, Interpretation::Move), Ok(1));
+        assert_eq!(game.score_round(&round, Interpretation::Outcome), Ok(1));
+
+        let round = Round {
+            opponent: 0,
+            response: 'Z',
+        };
+
+        assert_eq!(game.score_round(&round, Interpretation::Move), Ok(3));
+        assert_eq!(game.score_round(&round, Interpretation::Outcome), Ok(8));
+    }
+
+    #[test]
+    fn test_different_strat() {
+        const DATA: &str = "A Y
+                            B X
+                            A Z
+                            C Z";
+        const EXPECTED: u32 = 20;
+        let actual = score(&Day2::parse(DATA).unwrap(), Interpretation::Outcome).unwrap();
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_unknown_choice_is_located() {
+        const DATA: &str = "A Y
+B Q";
+        let err = Day2::parse(DATA).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "Q");
+    }
+
+    #[test]
+    fn test_missing_column_is_located() {
+        let err = Day2::parse("A Y\n\nC\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
@@ -0,0 +1,236 @@
+//! A round by round account of following a strategy guide, for checking a
+//! guide before trusting it.
+
+use super::{Game, Interpretation, Round, RoundResult};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundReport {
+    /// The opponent's shape, as an index into the game's shapes.
+    pub opponent: usize,
+    pub mine: usize,
+    pub result: RoundResult,
+    pub shape_points: u32,
+    pub outcome_points: u32,
+}
+
+impl RoundReport {
+    pub fn points(&self) -> u32 {
+        self.shape_points + self.outcome_points
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report<'a> {
+    pub game: &'a Game,
+    pub rounds: Vec<RoundReport>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(
+        game: &'a Game,
+        rounds: &[Round],
+        interpretation: Interpretation,
+    ) -> Result<Self, String> {
+        let rounds = rounds
+            .iter()
+            .map(|round| {
+                let (mine, result) = game.play(round, interpretation)?;
+
+                Ok(RoundReport {
+                    opponent: round.opponent,
+                    mine,
+                    result,
+                    shape_points: game.shapes()[mine].score,
+                    outcome_points: game.outcome(result).score,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self { game, rounds })
+    }
+
+    pub fn total(&self) -> u32 {
+        self.rounds.iter().map(RoundReport::points).sum()
+    }
+
+    pub fn count(&self, result: RoundResult) -> usize {
+        self.rounds
+            .iter()
+            .filter(|round| round.result == result)
+            .count()
+    }
+
+    /// The points won in rounds where I played each shape, in the game's
+    /// shape order.
+    pub fn score_by_shape(&self) -> Vec<u32> {
+        let mut scores = vec![0; self.game.shapes().len()];
+        for round in &self.rounds {
+            scores[round.mine] += round.points();
+        }
+
+        scores
+    }
+
+    /// The total after each round.
+    pub fn running_scores(&self) -> Vec<u32> {
+        self.rounds
+            .iter()
+            .scan(0, |total, round| {
+                *total += round.points();
+                Some(*total)
+            })
+            .collect()
+    }
+
+    fn name(&self, shape: usize) -> &str {
+        &self.game.shapes()[shape].name
+    }
+
+    pub fn format_table(&self) -> String {
+        let width = self
+            .game
+            .shapes()
+            .iter()
+            .map(|shape| shape.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("Opponent".len());
+
+        let mut table = format!(
+            "Round  {:<width$}  {:<width$}  Result  Shape  Outcome  Running\n",
+            "Opponent", "Me"
+        );
+        for (idx, (round, running)) in self.rounds.iter().zip(self.running_scores()).enumerate() {
+            table += &format!(
+                "{:>5}  {:<width$}  {:<width$}  {:<6}  {:>5}  {:>7}  {:>7}\n",
+                idx + 1,
+                self.name(round.opponent),
+                self.name(round.mine),
+                format!("{:?}", round.result),
+                round.shape_points,
+                round.outcome_points,
+                running
+            );
+        }
+
+        table += &format!(
+            "\n{} wins, {} draws, {} losses, {} points\n",
+            self.count(RoundResult::Win),
+            self.count(RoundResult::Draw),
+            self.count(RoundResult::Lose),
+            self.total()
+        );
+        for (shape, points) in self.game.shapes().iter().zip(self.score_by_shape()) {
+            table += &format!("{:<width$}  {points:>7}\n", shape.name);
+        }
+
+        table
+    }
+
+    /// One row per round, for loading into a spreadsheet.
+    pub fn to_csv(&self) -> String {
+        let mut csv = "round,opponent,me,result,shape_points,outcome_points,running\n".to_string();
+        for (idx, (round, running)) in self.rounds.iter().zip(self.running_scores()).enumerate() {
+            csv += &format!(
+                "{},{},{},{:?},{},{},{running}\n",
+                idx + 1,
+                csv_field(self.name(round.opponent)),
+                csv_field(self.name(round.mine)),
+                round.result,
+                round.shape_points,
+                round.outcome_points
+            );
+        }
+
+        csv
+    }
+}
+
+/// Quotes a field that holds a comma, quote or line break, doubling any
+/// quotes inside it.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Day2;
+    use super::*;
+    use crate::solution::Solution;
+
+    #[test]
+    fn test_example_report() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+        let report = Report::new(&game, &rounds, Interpretation::Outcome).unwrap();
+
+        assert_eq!(report.total(), 12);
+        assert_eq!(report.running_scores(), vec![4, 5, 12]);
+        assert_eq!(report.score_by_shape(), vec![12, 0, 0]);
+        assert_eq!(
+            (
+                report.count(RoundResult::Win),
+                report.count(RoundResult::Draw),
+                report.count(RoundResult::Lose)
+            ),
+            (1, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+        let report = Report::new(&game, &rounds, Interpretation::Move).unwrap();
+
+        let expected = "\
+Round  Opponent  Me        Result  Shape  Outcome  Running
+    1  Rock      Paper     Win         2        6        8
+    2  Paper     Rock      Lose        1        0        9
+    3  Scissors  Scissors  Draw        3        3       15
+
+1 wins, 1 draws, 1 losses, 15 points
+Rock            1
+Paper           8
+Scissors        6
+";
+
+        assert_eq!(report.format_table(), expected);
+    }
+
+    #[test]
+    fn test_csv() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day2::parse("A Y\nB X").unwrap();
+        let report = Report::new(&game, &rounds, Interpretation::Outcome).unwrap();
+
+        assert_eq!(
+            report.to_csv(),
+            "round,opponent,me,result,shape_points,outcome_points,running\n\
+             1,Rock,Rock,Draw,1,3,4\n\
+             2,Paper,Rock,Lose,1,0,5\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_quotes_awkward_names() {
+        let game = Game::parse(
+            "shape Rock,Hard A X 1\nshape \"Paper\" B Y 2\nshape Scissors C Z 3\n\
+             lose X 0\ndraw Y 3\nwin Z 6",
+        )
+        .unwrap();
+        let rounds = Day2::parse("A Y\nB X").unwrap();
+        let report = Report::new(&game, &rounds, Interpretation::Move).unwrap();
+
+        assert_eq!(
+            report.to_csv(),
+            "round,opponent,me,result,shape_points,outcome_points,running\n\
+             1,\"Rock,Hard\",\"\"\"Paper\"\"\",Win,2,6,8\n\
+             2,\"\"\"Paper\"\"\",\"Rock,Hard\",Lose,1,0,9\n"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! What I should play: the best response when the opponent's moves are
+//! known, and the best mix of shapes against a model of the opponent when
+//! they're only guessed at.
+
+use super::{Game, Round};
+
+/// Mixed strategies within this of the best expected score count as tied.
+const EPSILON: f64 = 1e-9;
+
+/// The shape that scores the most against `opponent`.
+pub fn best_response(game: &Game, opponent: usize) -> usize {
+    (0..game.shapes().len())
+        .max_by_key(|mine| (points(game, *mine, opponent), std::cmp::Reverse(*mine)))
+        .expect("a game has at least three shapes")
+}
+
+/// The best response to each round's opponent.
+pub fn best_responses(game: &Game, rounds: &[Round]) -> Vec<usize> {
+    rounds
+        .iter()
+        .map(|round| best_response(game, round.opponent))
+        .collect()
+}
+
+/// The most any guide could score against these opponent moves.
+pub fn max_score(game: &Game, rounds: &[Round]) -> u32 {
+    rounds
+        .iter()
+        .map(|round| points(game, best_response(game, round.opponent), round.opponent))
+        .sum()
+}
+
+fn points(game: &Game, mine: usize, opponent: usize) -> u32 {
+    game.shapes()[mine].score + game.outcome(game.result(mine, opponent)).score
+}
+
+/// A guess at what the opponent plays, learned from a guide's first column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpponentModel {
+    /// Each shape is played with a fixed probability, regardless of history.
+    Distribution(Vec<f64>),
+    /// The next shape depends on the previous one: `transitions[prev][next]`.
+    /// `first` is used for the opening round.
+    Markov {
+        first: Vec<f64>,
+        transitions: Vec<Vec<f64>>,
+    },
+}
+
+impl OpponentModel {
+    /// How often the opponent played each shape.
+    pub fn distribution(game: &Game, rounds: &[Round]) -> Self {
+        Self::Distribution(frequencies(game, rounds.iter().map(|round| round.opponent)))
+    }
+
+    /// How often each shape followed each other shape. Shapes that were
+    /// never followed by anything fall back to the overall distribution.
+    pub fn markov(game: &Game, rounds: &[Round]) -> Self {
+        let overall = frequencies(game, rounds.iter().map(|round| round.opponent));
+        let first = frequencies(game, rounds.first().map(|round| round.opponent));
+        let transitions = (0..game.shapes().len())
+            .map(|prev| {
+                let next = rounds
+                    .windows(2)
+                    .filter(|pair| pair[0].opponent == prev)
+                    .map(|pair| pair[1].opponent);
+                let row = frequencies(game, next);
+                if row.iter().all(|p| *p == 0.0) {
+                    overall.clone()
+                } else {
+                    row
+                }
+            })
+            .collect();
+
+        Self::Markov {
+            first: if first.iter().all(|p| *p == 0.0) {
+                overall
+            } else {
+                first
+            },
+            transitions,
+        }
+    }
+
+    /// The chance of each shape next, given the opponent's previous shape
+    /// (`None` before the first round).
+    pub fn predict(&self, previous: Option<usize>) -> &[f64] {
+        match (self, previous) {
+            (Self::Distribution(probabilities), _) => probabilities,
+            (Self::Markov { first, .. }, None) => first,
+            (Self::Markov { transitions, .. }, Some(prev)) => &transitions[prev],
+        }
+    }
+}
+
+/// Normalised counts of `shapes`, or all zeros if there are none.
+fn frequencies(game: &Game, shapes: impl IntoIterator<Item = usize>) -> Vec<f64> {
+    let mut counts = vec![0.0; game.shapes().len()];
+    let mut total = 0.0;
+    for shape in shapes {
+        counts[shape] += 1.0;
+        total += 1.0;
+    }
+
+    if total > 0.0 {
+        counts.iter_mut().for_each(|count| *count /= total);
+    }
+    counts
+}
+
+/// A probability for playing each shape, and what it scores on average.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixedStrategy {
+    pub weights: Vec<f64>,
+    pub expected_score: f64,
+}
+
+/// The expected score of each shape against an opponent who plays shape
+/// `i` with probability `probabilities[i]`.
+pub fn expected_scores(game: &Game, probabilities: &[f64]) -> Vec<f64> {
+    (0..game.shapes().len())
+        .map(|mine| {
+            probabilities
+                .iter()
+                .enumerate()
+                .map(|(opponent, p)| p * f64::from(points(game, mine, opponent)))
+                .sum()
+        })
+        .collect()
+}
+
+/// The mixed strategy with the highest expected score. The expected score is
+/// linear in the weights, so nothing beats the best single shape; when
+/// several shapes tie for best the weight is split evenly between them.
+pub fn best_mix(game: &Game, probabilities: &[f64]) -> MixedStrategy {
+    let scores = expected_scores(game, probabilities);
+    let best = scores.iter().copied().fold(f64::MIN, f64::max);
+    let tied = scores.iter().filter(|s| best - **s < EPSILON).count() as f64;
+
+    MixedStrategy {
+        weights: scores
+            .iter()
+            .map(|s| if best - s < EPSILON { 1.0 / tied } else { 0.0 })
+            .collect(),
+        expected_score: best,
+    }
+}
+
+/// Plays the best mix against `model` in each round, updating on the
+/// opponent's real moves, and returns the strategy used for each round and
+/// the total expected score.
+pub fn play_against_model(
+    game: &Game,
+    rounds: &[Round],
+    model: &OpponentModel,
+) -> (Vec<MixedStrategy>, f64) {
+    let strategies: Vec<MixedStrategy> = rounds
+        .iter()
+        .enumerate()
+        .map(|(idx, _)| {
+            let previous = idx.checked_sub(1).map(|prev| rounds[prev].opponent);
+            best_mix(game, model.predict(previous))
+        })
+        .collect();
+    let expected = strategies.iter().map(|mix| mix.expected_score).sum();
+
+    (strategies, expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Day2, Interpretation};
+    use super::*;
+    use crate::solution::Solution;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_best_responses() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+
+        // Paper beats rock for 8, scissors beats paper for 9, rock beats
+        // scissors for 7.
+        assert_eq!(best_responses(&game, &rounds), vec![1, 2, 0]);
+        assert_eq!(max_score(&game, &rounds), 24);
+        assert!(max_score(&game, &rounds) >= game.score(&rounds, Interpretation::Move).unwrap());
+    }
+
+    #[test]
+    fn test_best_mix_against_uniform_opponent() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day2::parse("A Y\nB X\nC Z").unwrap();
+        let model = OpponentModel::distribution(&game, &rounds);
+
+        let scores = expected_scores(&game, model.predict(None));
+        assert!(close(scores[0], 4.0) && close(scores[1], 5.0) && close(scores[2], 6.0));
+
+        // Every result is equally likely, so the shape score decides.
+        let mix = best_mix(&game, model.predict(None));
+        assert_eq!(mix.weights, vec![0.0, 0.0, 1.0]);
+        assert!(close(mix.expected_score, 6.0));
+    }
+
+    #[test]
+    fn test_ties_split_the_weight() {
+        let game = Game::rock_paper_scissors();
+        // Paper scores 8 against rock and 5 against paper, scissors 3 and
+        // 9, so they tie when rock is 4/5 as likely as paper.
+        let mix = best_mix(&game, &[4.0 / 9.0, 5.0 / 9.0, 0.0]);
+
+        assert_eq!(mix.weights, vec![0.0, 0.5, 0.5]);
+        assert!(close(mix.expected_score, 57.0 / 9.0));
+    }
+
+    #[test]
+    fn test_markov_model() {
+        let game = Game::rock_paper_scissors();
+        let rounds = Day2::parse("A Y\nA X\nB Z\nA Y").unwrap();
+        let model = OpponentModel::markov(&game, &rounds);
+
+        assert_eq!(model.predict(None), &[1.0, 0.0, 0.0]);
+        assert_eq!(model.predict(Some(0)), &[0.5, 0.5, 0.0]);
+        assert_eq!(model.predict(Some(1)), &[1.0, 0.0, 0.0]);
+        // Scissors never came up, so fall back to how often each was played.
+        assert_eq!(model.predict(Some(2)), &[0.75, 0.25, 0.0]);
+
+        let (strategies, expected) = play_against_model(&game, &rounds, &model);
+        assert_eq!(strategies.len(), 4);
+        // Paper against a sure rock scores 8; after a rock the opponent is
+        // as likely to play paper, and paper still does best with 6.5.
+        assert!(close(expected, 8.0 + 6.5 + 6.5 + 8.0));
+    }
+}
@@ -0,0 +1,276 @@
+//! Finding the badge groups when the rucksacks aren't listed group by group.
+
+use std::collections::BTreeSet;
+
+use super::{BadgeError, ItemSet, Rucksack};
+
+/// How many partial groups the search tries before giving up.
+pub const DISCOVERY_SEARCH_BUDGET: u64 = 10_000_000;
+
+/// Explanations skip the search for inputs with more rucksacks than this, as
+/// a group per line would bury the packing report anyway.
+pub const DISCOVERY_RUCKSACK_LIMIT: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grouping {
+    /// Indices into the rucksacks, each group in increasing order and the
+    /// groups ordered by their first member.
+    pub groups: Vec<Vec<usize>>,
+    /// Each group's badge.
+    pub badges: Vec<char>,
+    /// Whether this is the only way to split the rucksacks up, or `None` if
+    /// the search ran out of budget before it could tell.
+    pub unique: Option<bool>,
+}
+
+impl Grouping {
+    /// A line per group with its members, counted from one, and its badge,
+    /// then whether the grouping is the only one.
+    pub fn format(&self) -> String {
+        let mut text = String::new();
+
+        for (idx, (group, badge)) in self.groups.iter().zip(&self.badges).enumerate() {
+            let members: Vec<String> = group.iter().map(|idx| (idx + 1).to_string()).collect();
+            text += &format!(
+                "Group {}: rucksacks {}, badge {badge}\n",
+                idx + 1,
+                members.join(", ")
+            );
+        }
+        text += match self.unique {
+            Some(true) => "This is the only way to group them.\n",
+            Some(false) => "The rucksacks can be grouped other ways too.\n",
+            None => "Gave up before finding out if they can be grouped other ways.\n",
+        };
+
+        text
+    }
+}
+
+/// Splits `rucksacks` into groups of `group_size` that each share exactly
+/// one item type, in whatever order the rucksacks come.
+///
+/// The search always starts a group with the first rucksack not yet placed
+/// and adds members in index order, so each split is only tried once, and it
+/// drops a partial group as soon as its members have nothing in common.
+pub fn discover_groups(rucksacks: &[Rucksack], group_size: usize) -> Result<Grouping, BadgeError> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(BadgeError::Remainder {
+            rucksacks: rucksacks.len(),
+            group_size,
+        });
+    }
+
+    let mut search = Search {
+        contents: rucksacks.iter().map(Rucksack::contents).collect(),
+        group_size,
+        unplaced: (0..rucksacks.len()).collect(),
+        found: Vec::new(),
+        budget: DISCOVERY_SEARCH_BUDGET,
+    };
+    search.run();
+    let finished = search.budget > 0;
+
+    let mut found = search.found.into_iter();
+    let Some(groups) = found.next() else {
+        return Err(if finished {
+            BadgeError::NoGrouping
+        } else {
+            BadgeError::SearchGaveUp
+        });
+    };
+    let unique = if found.next().is_some() {
+        Some(false)
+    } else {
+        finished.then_some(true)
+    };
+
+    let badges = groups
+        .iter()
+        .map(|group| {
+            group
+                .iter()
+                .map(|idx| rucksacks[*idx].contents())
+                .reduce(|shared, items| shared & items)
+                .unwrap_or_default()
+                .iter()
+                .next()
+                .expect("every group found has a badge")
+        })
+        .collect();
+
+    Ok(Grouping {
+        groups,
+        badges,
+        unique,
+    })
+}
+
+struct Search {
+    contents: Vec<ItemSet>,
+    group_size: usize,
+    unplaced: BTreeSet<usize>,
+    /// The complete splits found so far. Two is enough to know the answer
+    /// isn't unique.
+    found: Vec<Vec<Vec<usize>>>,
+    budget: u64,
+}
+
+impl Search {
+    fn done(&self) -> bool {
+        self.found.len() >= 2 || self.budget == 0
+    }
+
+    /// A depth first search over which rucksack fills the next place in the
+    /// current group. The stack holds the rucksacks placed so far, in order,
+    /// each with the items its group has in common up to and including it,
+    /// so backtracking is a pop rather than a return and the depth isn't
+    /// limited by the call stack.
+    fn run(&mut self) {
+        let mut stack: Vec<(usize, ItemSet)> = Vec::new();
+        // The lowest rucksack worth trying for the next place.
+        let mut next = 0;
+
+        while !self.done() {
+            self.budget -= 1;
+
+            if stack.len() == self.contents.len() {
+                let order: Vec<usize> = stack.iter().map(|(idx, _)| *idx).collect();
+                self.found.push(
+                    order
+                        .chunks(self.group_size)
+                        .map(<[usize]>::to_vec)
+                        .collect(),
+                );
+            } else if let Some((idx, shared)) = self.candidate(&stack, next) {
+                self.unplaced.remove(&idx);
+                stack.push((idx, shared));
+                next = 0;
+                continue;
+            }
+
+            let Some((idx, _)) = stack.pop() else {
+                return;
+            };
+            self.unplaced.insert(idx);
+            next = idx + 1;
+        }
+    }
+
+    /// The first rucksack from `next` on that can take the next place, and
+    /// what its group would then share. A group always starts with the first
+    /// rucksack not yet placed, so each split is only tried once, and adds
+    /// members in index order; a partial group is dropped as soon as its
+    /// members have nothing in common.
+    fn candidate(&mut self, stack: &[(usize, ItemSet)], next: usize) -> Option<(usize, ItemSet)> {
+        let place = stack.len() % self.group_size;
+        let fits = |shared: ItemSet| {
+            if place + 1 == self.group_size {
+                shared.len() == 1
+            } else {
+                !shared.is_empty()
+            }
+        };
+
+        if place == 0 {
+            let first = *self.unplaced.first()?;
+            let shared = self.contents[first];
+            return (next <= first && fits(shared)).then_some((first, shared));
+        }
+
+        let (last, group_shared) = *stack.last().expect("a partial group has members");
+        for &idx in self.unplaced.range(next.max(last + 1)..) {
+            if self.budget == 0 {
+                return None;
+            }
+            self.budget -= 1;
+
+            let shared = group_shared & self.contents[idx];
+            if fits(shared) {
+                return Some((idx, shared));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rucksacks(items: &[&str]) -> Vec<Rucksack> {
+        items
+            .iter()
+            .map(|items| Rucksack::new(items).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_shuffled_example() {
+        let example = rucksacks(&[
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "ttgJtRGJQctTZtZT",
+            "PmmdzqPrVvPwwTWBwg",
+        ]);
+        let grouping = discover_groups(&example, 3).unwrap();
+
+        assert_eq!(grouping.groups, vec![vec![0, 2, 4], vec![1, 3, 5]]);
+        assert_eq!(grouping.badges, vec!['Z', 'r']);
+        // The example's rucksacks happen to share enough items that they can
+        // be grouped another way too.
+        assert_eq!(grouping.unique, Some(false));
+    }
+
+    #[test]
+    fn test_unique_grouping() {
+        let grouping =
+            discover_groups(&rucksacks(&["ac", "bf", "ad", "bg", "ae", "bh"]), 3).unwrap();
+
+        assert_eq!(grouping.groups, vec![vec![0, 2, 4], vec![1, 3, 5]]);
+        assert_eq!(grouping.badges, vec!['a', 'b']);
+        assert_eq!(grouping.unique, Some(true));
+        assert_eq!(
+            grouping.format(),
+            "Group 1: rucksacks 1, 3, 5, badge a
+Group 2: rucksacks 2, 4, 6, badge b
+This is the only way to group them.
+"
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_grouping() {
+        // ab+ac share a and bd+cd share d, but ab+bd share b and ac+cd c.
+        let grouping = discover_groups(&rucksacks(&["ab", "ac", "bd", "cd"]), 2).unwrap();
+
+        assert_eq!(grouping.unique, Some(false));
+    }
+
+    #[test]
+    fn test_many_rucksacks() {
+        let grouping = discover_groups(&vec![Rucksack::new("aa").unwrap(); 300_000], 3).unwrap();
+
+        assert_eq!(grouping.groups.len(), 100_000);
+        assert_eq!(grouping.groups[99_999], vec![299_997, 299_998, 299_999]);
+        assert_eq!(grouping.unique, Some(false));
+
+        let grouping = discover_groups(&vec![Rucksack::new("aa").unwrap(); 300_000], 1).unwrap();
+        assert_eq!(grouping.unique, Some(true));
+    }
+
+    #[test]
+    fn test_no_grouping() {
+        assert_eq!(
+            discover_groups(&rucksacks(&["ab", "cd"]), 2),
+            Err(BadgeError::NoGrouping)
+        );
+        assert!(matches!(
+            discover_groups(&rucksacks(&["ab", "cd"]), 3),
+            Err(BadgeError::Remainder { .. })
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::{BitAnd, BitOr};
+
+/// A set of item types, one bit per letter: `a` to `z` are bits 0 to 25 and
+/// `A` to `Z` bits 26 to 51, so bit `n` is the item with priority `n + 1`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: Self = Self(0);
+
+    /// The set's bit for `item`, or `None` if it isn't an ASCII letter.
+    fn bit(item: char) -> Option<u32> {
+        match item {
+            'a'..='z' => Some(item as u32 - 'a' as u32),
+            'A'..='Z' => Some(item as u32 - 'A' as u32 + 26),
+            _ => None,
+        }
+    }
+
+    fn item(bit: u32) -> char {
+        if bit < 26 {
+            char::from(b'a' + bit as u8)
+        } else {
+            char::from(b'A' + (bit - 26) as u8)
+        }
+    }
+
+    /// The priority of an item: 1 to 26 for `a` to `z`, 27 to 52 for `A` to
+    /// `Z`, and `None` for anything else.
+    pub fn priority(item: char) -> Option<i32> {
+        Self::bit(item).map(|bit| bit as i32 + 1)
+    }
+
+    /// Adds `item`, returning whether it is an item type. Anything other
+    /// than an ASCII letter leaves the set as it was.
+    pub fn insert(&mut self, item: char) -> bool {
+        match Self::bit(item) {
+            Some(bit) => {
+                self.0 |= 1 << bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        Self::bit(item).is_some_and(|bit| self.0 & (1 << bit) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The items in priority order.
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let bits = self.0;
+        (0..52)
+            .filter(move |bit| bits & (1 << bit) != 0)
+            .map(Self::item)
+    }
+
+    pub fn priority_sum(&self) -> i32 {
+        self.iter().filter_map(Self::priority).sum()
+    }
+}
+
+/// Collects the item types among the characters, skipping anything else.
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(items: I) -> Self {
+        let mut set = Self::EMPTY;
+        for item in items {
+            set.insert(item);
+        }
+
+        set
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let left: ItemSet = "vJrwpWtwJgWr".chars().collect();
+        let right: ItemSet = "hcsFMMfFFhFp".chars().collect();
+
+        assert_eq!((left & right).iter().collect::<String>(), "p");
+        assert_eq!((left | right).len(), 14);
+        assert!(left.contains('J') && !left.contains('j') && !left.contains('1'));
+        assert!(ItemSet::EMPTY.is_empty());
+    }
+
+    #[test]
+    fn test_priorities() {
+        let all: ItemSet = ('a'..='z').chain('A'..='Z').collect();
+
+        assert_eq!(all.len(), 52);
+        assert_eq!(all.priority_sum(), (1..=52).sum());
+        assert_eq!(all.iter().nth(26), Some('A'));
+        assert_eq!(ItemSet::priority('L'), Some(38));
+        assert_eq!(ItemSet::priority('é'), None);
+    }
+
+    #[test]
+    fn test_non_items_are_skipped() {
+        let mut set: ItemSet = "a1b-".chars().collect();
+        assert_eq!(set.iter().collect::<String>(), "ab");
+
+        assert!(!set.insert('é'));
+        assert!(set.insert('C'));
+        assert_eq!(set.len(), 3);
+    }
+}
@@ -0,0 +1,360 @@
+pub mod groups;
+pub mod item_set;
+pub mod report;
+pub mod rucksack;
+
+use std::fmt;
+
+pub use groups::{discover_groups, DISCOVERY_RUCKSACK_LIMIT};
+pub use item_set::ItemSet;
+pub use report::PackingReport;
+pub use rucksack::{Rucksack, RucksackError};
+
+use crate::error::{non_blank_lines, ParseError};
+use crate::solution::Solution;
+
+/// How many elves share a badge in part two.
+pub const GROUP_SIZE: usize = 3;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type PartOne = i32;
+    type PartTwo = Result<i32, BadgeError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        non_blank_lines(input)
+            .map(|(idx, line)| {
+                let trimmed = line.trim();
+                Rucksack::new(trimmed).map_err(|err| match err {
+                    RucksackError::NotAnItem { offset, item } => {
+                        let text = &trimmed[offset..offset + item.len_utf8()];
+                        ParseError::at(Self::DAY, idx, line, text, err.to_string())
+                    }
+                    RucksackError::OddLength { .. } => {
+                        ParseError::line(Self::DAY, idx, line, err.to_string())
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let (_, result) = calculate_total_priority(input);
+        result
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let (_, result) = calculate_badge_priority(input, GROUP_SIZE)?;
+        Ok(result)
+    }
+
+    /// The packing report for part one, then the badge groups found without
+    /// assuming the rucksacks are listed group by group, for inputs of up to
+    /// `DISCOVERY_RUCKSACK_LIMIT` rucksacks.
+    fn explain(input: &Self::Input) -> Option<String> {
+        let mut text = PackingReport::new(input).format();
+
+        text += "\n";
+        if input.len() > DISCOVERY_RUCKSACK_LIMIT {
+            text += &format!(
+                "Badge groups not searched for, {} rucksacks is more than {DISCOVERY_RUCKSACK_LIMIT}\n",
+                input.len()
+            );
+            return Some(text);
+        }
+        match discover_groups(input, GROUP_SIZE) {
+            Ok(grouping) => text += &grouping.format(),
+            Err(err) => text += &format!("No badge groups: {err}\n"),
+        }
+
+        Some(text)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BadgeError {
+    /// The rucksacks don't split evenly into groups.
+    Remainder { rucksacks: usize, group_size: usize },
+    /// A group, counted from zero, has no item in common.
+    NoBadge { group: usize },
+    /// A group has more than one item in common, so the badge is ambiguous.
+    MultipleBadges { group: usize, badges: Vec<char> },
+    /// There's no way to split the rucksacks into groups with one badge each.
+    NoGrouping,
+    /// The search for groups gave up before finding any.
+    SearchGaveUp,
+}
+
+impl fmt::Display for BadgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Remainder {
+                rucksacks,
+                group_size,
+            } => write!(
+                f,
+                "{rucksacks} rucksacks don't make groups of {group_size}, {} left over",
+                rucksacks.checked_rem(*group_size).unwrap_or(*rucksacks)
+            ),
+            Self::NoBadge { group } => write!(f, "group {} has no badge in common", group + 1),
+            Self::MultipleBadges { group, badges } => write!(
+                f,
+                "group {} has {} possible badges: {}",
+                group + 1,
+                badges.len(),
+                badges.iter().collect::<String>()
+            ),
+            Self::NoGrouping => write!(f, "the rucksacks can't be split into badge groups"),
+            Self::SearchGaveUp => write!(f, "gave up looking for badge groups"),
+        }
+    }
+}
+
+impl std::error::Error for BadgeError {}
+
+pub fn calculate_total_priority(rucksacks: &[Rucksack]) -> (usize, i32) {
+    let result = rucksacks
+        .iter()
+        .map(|rucksack| rucksack.misplaced().priority_sum())
+        .sum();
+
+    (rucksacks.len(), result)
+}
+
+/// The one item carried by every elf in each group of `group_size`
+/// consecutive rucksacks.
+pub fn find_badges(rucksacks: &[Rucksack], group_size: usize) -> Result<Vec<char>, BadgeError> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(BadgeError::Remainder {
+            rucksacks: rucksacks.len(),
+            group_size,
+        });
+    }
+
+    rucksacks
+        .chunks_exact(group_size)
+        .enumerate()
+        .map(|(group, members)| {
+            let badges: Vec<char> = members
+                .iter()
+                .map(Rucksack::contents)
+                .reduce(|shared, items| shared & items)
+                .unwrap_or_default()
+                .iter()
+                .collect();
+
+            match badges[..] {
+                [badge] => Ok(badge),
+                [] => Err(BadgeError::NoBadge { group }),
+                _ => Err(BadgeError::MultipleBadges { group, badges }),
+            }
+        })
+        .collect()
+}
+
+pub fn calculate_badge_priority(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+) -> Result<(usize, i32), BadgeError> {
+    let badges = find_badges(rucksacks, group_size)?;
+    let result = badges.iter().map(|c| calculate_priority(*c)).sum();
+
+    Ok((rucksacks.len(), result))
+}
+
+pub fn calculate_priority(c: char) -> i32 {
+    ItemSet::priority(c).unwrap_or_else(|| panic!("{c:?} is not an item type."))
+}
+
+/// The item types in both compartments, in priority order. Anything that
+/// isn't an item type is ignored.
+pub fn find_shared_items(left: &str, right: &str) -> Vec<char> {
+    let left: ItemSet = left.chars().collect();
+    let right: ItemSet = right.chars().collect();
+
+    (left & right).iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_example_priorities() {
+        let data = [
+            ('p', 16),
+            ('L', 38),
+            ('P', 42),
+            ('v', 22),
+            ('t', 20),
+            ('s', 19),
+        ];
+
+        data.iter().for_each(|(c, expected)| {
+            let actual = calculate_priority(*c);
+            println!("Calculating priority for character {c}. Expecting {expected}. Got {actual}.");
+            assert_eq!(actual, *expected);
+        });
+    }
+
+    #[test]
+    fn test_find_shared_items_example() {
+        const LEFT: &str = "vJrwpWtwJgWr";
+        const RIGHT: &str = "hcsFMMfFFhFp";
+
+        let actual = find_shared_items(LEFT, RIGHT);
+        let expected = ['p'].to_vec();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_find_shared_items_but_prune_duplicates() {
+        const LEFT: &str = "jqHRNqRjqzjGDLGL";
+        const RIGHT: &str = "rsFMfFZSrLrFZsSL";
+
+        let actual = find_shared_items(LEFT, RIGHT);
+        let expected = ['L'].to_vec();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_find_shared_items_ignores_non_items() {
+        assert_eq!(find_shared_items("a1", "b1"), Vec::<char>::new());
+        assert_eq!(find_shared_items("a1-Q", "Q-1a"), vec!['a', 'Q']);
+    }
+
+    #[test]
+    fn test_example_data() {
+        const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+        let (count, result) = calculate_total_priority(&Day3::parse(EXAMPLE).unwrap());
+
+        const EXPECTED_COUNT: usize = 6;
+        const EXPECTED_RESULT: i32 = 157;
+        assert_eq!(count, EXPECTED_COUNT);
+        assert_eq!(result, EXPECTED_RESULT);
+    }
+
+    #[test]
+    fn test_example_badges() {
+        const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+        let (count, result) =
+            calculate_badge_priority(&Day3::parse(EXAMPLE).unwrap(), GROUP_SIZE).unwrap();
+
+        const EXPECTED_COUNT: usize = 6;
+        const EXPECTED_RESULT: i32 = 70;
+        assert_eq!(count, EXPECTED_COUNT);
+        assert_eq!(result, EXPECTED_RESULT);
+    }
+
+    #[test]
+    fn test_configurable_group_size() {
+        let rucksacks: Vec<Rucksack> = ["ab", "bc", "bd", "ef"]
+            .iter()
+            .map(|s| Rucksack::new(s).unwrap())
+            .collect();
+
+        assert_eq!(find_badges(&rucksacks[..3], 3), Ok(vec!['b']));
+        assert_eq!(
+            find_badges(&rucksacks, 2),
+            Err(BadgeError::NoBadge { group: 1 })
+        );
+        assert_eq!(
+            find_badges(&rucksacks, 3),
+            Err(BadgeError::Remainder {
+                rucksacks: 4,
+                group_size: 3
+            })
+        );
+        assert_eq!(
+            find_badges(&rucksacks[..2], 1),
+            Err(BadgeError::MultipleBadges {
+                group: 0,
+                badges: vec!['a', 'b']
+            })
+        );
+    }
+
+    #[test]
+    fn test_bad_groups_only_fail_part_two() {
+        let rucksacks = Day3::parse("abXY\nbcXZ\nbdZY\nefgh\n").unwrap();
+        assert_eq!(Day3::part_one(&rucksacks), 0);
+        assert_eq!(
+            Day3::part_two(&rucksacks).unwrap_err().to_string(),
+            "4 rucksacks don't make groups of 3, 1 left over"
+        );
+
+        let rucksacks = Day3::parse("abXY\nbcXZ\nbdZY\nefgh\nijkl\nmnop").unwrap();
+        assert_eq!(
+            Day3::part_two(&rucksacks),
+            Err(BadgeError::NoBadge { group: 1 })
+        );
+
+        let rucksacks = Day3::parse("abXY\nabXZ\nabZY").unwrap();
+        assert_eq!(
+            Day3::part_two(&rucksacks).unwrap_err().to_string(),
+            "group 1 has 2 possible badges: ab"
+        );
+    }
+
+    #[test]
+    fn test_explain_finds_unordered_groups() {
+        let rucksacks = Day3::parse(
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+vJrwpWtwJgWrhcsFMMfFFhFp
+CrZsJsPPZsGzwwsLwLmpwMDw
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+ttgJtRGJQctTZtZT
+PmmdzqPrVvPwwTWBwg",
+        )
+        .unwrap();
+        let text = Day3::explain(&rucksacks).unwrap();
+
+        assert!(text.contains("Total priority: 157\n"));
+        assert!(text.contains("Group 1: rucksacks 1, 3, 5, badge Z\n"));
+        assert!(text.contains("Group 2: rucksacks 2, 4, 6, badge r\n"));
+
+        let text = Day3::explain(&Day3::parse("ab\ncd").unwrap()).unwrap();
+        assert!(
+            text.ends_with("No badge groups: 2 rucksacks don't make groups of 3, 2 left over\n")
+        );
+    }
+
+    #[test]
+    fn test_explain_skips_groups_for_large_inputs() {
+        let rucksacks = vec![Rucksack::new("aa").unwrap(); DISCOVERY_RUCKSACK_LIMIT + 3];
+        let text = Day3::explain(&rucksacks).unwrap();
+
+        assert!(text.contains("Total priority: 10003\n"));
+        assert!(
+            text.ends_with("Badge groups not searched for, 10003 rucksacks is more than 10000\n")
+        );
+    }
+
+    #[test]
+    fn test_invalid_items_are_located() {
+        let err = Day3::parse("abXY\nab-Y\nbdZY").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "-"));
+
+        let err = Day3::parse("abXY\n  abXYZ\nbdZY").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.reason, "expected an even number of items, found 5");
+    }
+}
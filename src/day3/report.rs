@@ -0,0 +1,220 @@
+//! Explains part one: what's misplaced in each rucksack and how to fix it.
+
+use super::{calculate_priority, find_shared_items, Rucksack};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RucksackReport {
+    /// The rucksack's position in the input, counted from zero.
+    pub index: usize,
+    pub left: String,
+    pub right: String,
+    /// The item types found in both compartments.
+    pub misplaced: Vec<char>,
+    pub priority: i32,
+    /// The compartments with every copy of each misplaced item moved to the
+    /// left, swapping other items across to keep the halves the same size.
+    /// `None` if that can't be done without splitting up another item type.
+    pub repacked: Option<(String, String)>,
+}
+
+impl RucksackReport {
+    pub fn new(index: usize, rucksack: &Rucksack) -> Self {
+        let (left, right) = rucksack.compartments();
+        let misplaced = find_shared_items(left, right);
+        let priority = misplaced.iter().map(|c| calculate_priority(*c)).sum();
+        let repacked = repack(left, right, &misplaced);
+
+        Self {
+            index,
+            left: left.to_string(),
+            right: right.to_string(),
+            misplaced,
+            priority,
+            repacked,
+        }
+    }
+}
+
+/// Moves the misplaced items out of `from` into `to`, swapping them for
+/// other item types in `to`. Those are moved across whole, so they don't end
+/// up in both compartments instead; finding types that add up to the right
+/// number of items is a subset sum over their counts.
+fn repack(to: &str, from: &str, misplaced: &[char]) -> Option<(String, String)> {
+    let mut to: Vec<char> = to.chars().collect();
+    let mut from: Vec<char> = from.chars().collect();
+    let moving: Vec<usize> = (0..from.len())
+        .filter(|idx| misplaced.contains(&from[*idx]))
+        .collect();
+
+    let mut types: Vec<(char, usize)> = Vec::new();
+    for item in to.iter().filter(|item| !misplaced.contains(item)) {
+        match types.iter_mut().find(|(c, _)| c == item) {
+            Some((_, count)) => *count += 1,
+            None => types.push((*item, 1)),
+        }
+    }
+
+    // reached[sum] is the type that first made `sum` reachable.
+    let mut reached: Vec<Option<usize>> = vec![None; moving.len() + 1];
+    for (idx, (_, count)) in types.iter().enumerate() {
+        for sum in (*count..=moving.len()).rev() {
+            if reached[sum].is_none() && (sum == *count || reached[sum - count].is_some()) {
+                reached[sum] = Some(idx);
+            }
+        }
+    }
+
+    let mut swapping = Vec::new();
+    let mut sum = moving.len();
+    while sum > 0 {
+        let (item, count) = types[reached[sum]?];
+        swapping.push(item);
+        sum -= count;
+    }
+
+    let room: Vec<usize> = (0..to.len())
+        .filter(|idx| swapping.contains(&to[*idx]))
+        .collect();
+    for (from_idx, to_idx) in moving.into_iter().zip(room) {
+        std::mem::swap(&mut from[from_idx], &mut to[to_idx]);
+    }
+
+    Some((to.into_iter().collect(), from.into_iter().collect()))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackingReport {
+    pub rucksacks: Vec<RucksackReport>,
+}
+
+impl PackingReport {
+    pub fn new(rucksacks: &[Rucksack]) -> Self {
+        Self {
+            rucksacks: rucksacks
+                .iter()
+                .enumerate()
+                .map(|(index, rucksack)| RucksackReport::new(index, rucksack))
+                .collect(),
+        }
+    }
+
+    pub fn total_priority(&self) -> i32 {
+        self.rucksacks.iter().map(|report| report.priority).sum()
+    }
+
+    /// How many rucksacks each item type is misplaced in, most often first
+    /// and then by priority.
+    pub fn misplaced_counts(&self) -> Vec<(char, usize)> {
+        let mut counts: Vec<(char, usize)> = Vec::new();
+        for item in self.rucksacks.iter().flat_map(|report| &report.misplaced) {
+            match counts.iter_mut().find(|(c, _)| c == item) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*item, 1)),
+            }
+        }
+        counts.sort_by_key(|(c, count)| (std::cmp::Reverse(*count), calculate_priority(*c)));
+
+        counts
+    }
+
+    /// A section per rucksack with something misplaced, then the counts.
+    pub fn format(&self) -> String {
+        let mut text = String::new();
+
+        for report in self.rucksacks.iter().filter(|r| !r.misplaced.is_empty()) {
+            text += &format!(
+                "Rucksack {}: {} misplaced, priority {}\n  left:   {}\n  right:  {}\n",
+                report.index + 1,
+                report.misplaced.iter().collect::<String>(),
+                report.priority,
+                report.left,
+                report.right
+            );
+            match &report.repacked {
+                Some((left, right)) => text += &format!("  repack: {left} | {right}\n"),
+                None => text += "  repack: not possible without unpacking more\n",
+            }
+        }
+
+        text += &format!("\nTotal priority: {}\n", self.total_priority());
+        for (item, count) in self.misplaced_counts() {
+            text += &format!("{item}  {count:>4}\n");
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Day3;
+    use super::*;
+    use crate::solution::Solution;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn test_example_report() {
+        let report = PackingReport::new(&Day3::parse(EXAMPLE).unwrap());
+
+        assert_eq!(report.total_priority(), 157);
+        assert_eq!(
+            report.rucksacks[0].repacked,
+            Some(("pJrwpWtwJgWr".to_string(), "hcsFMMfFFhFv".to_string()))
+        );
+        assert_eq!(
+            report.misplaced_counts(),
+            vec![('p', 1), ('s', 1), ('t', 1), ('v', 1), ('L', 1), ('P', 1)]
+        );
+    }
+
+    #[test]
+    fn test_repacked_rucksacks_have_nothing_misplaced() {
+        for rucksack in Day3::parse(EXAMPLE).unwrap() {
+            let (left, right) = RucksackReport::new(0, &rucksack).repacked.unwrap();
+            let repacked = Rucksack::new(&(left + &right)).unwrap();
+
+            assert!(repacked.misplaced().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_repack_needs_room() {
+        let report = RucksackReport::new(0, &Rucksack::new("abaxay").unwrap());
+        assert_eq!(report.misplaced, vec!['a']);
+        assert_eq!(
+            report.repacked,
+            Some(("aaa".to_string(), "xby".to_string()))
+        );
+
+        // Four a's can't fit in a compartment of three.
+        let report = RucksackReport::new(0, &Rucksack::new("abaaay").unwrap());
+        assert_eq!(report.repacked, None);
+
+        // Swapping one b for the a would leave the other b behind.
+        let report = RucksackReport::new(0, &Rucksack::new("abbxya").unwrap());
+        assert_eq!(report.repacked, None);
+    }
+
+    #[test]
+    fn test_format() {
+        let report = PackingReport::new(&[Rucksack::new("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap()]);
+
+        assert_eq!(
+            report.format(),
+            "Rucksack 1: p misplaced, priority 16
+  left:   vJrwpWtwJgWr
+  right:  hcsFMMfFFhFp
+  repack: pJrwpWtwJgWr | hcsFMMfFFhFv
+
+Total priority: 16
+p     1
+"
+        );
+    }
+}
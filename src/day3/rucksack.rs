@@ -0,0 +1,118 @@
+use std::fmt;
+
+use super::ItemSet;
+
+/// A rucksack's items, split evenly between its two compartments. Every
+/// item is an ASCII letter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rucksack {
+    items: String,
+    left: ItemSet,
+    right: ItemSet,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RucksackError {
+    /// `item`, at byte `offset` in the line, isn't a letter.
+    NotAnItem { offset: usize, item: char },
+    /// The items can't be split evenly between the compartments.
+    OddLength { len: usize },
+}
+
+impl fmt::Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAnItem { .. } => write!(f, "expected an item, a letter from a to z or A to Z"),
+            Self::OddLength { len } => {
+                write!(f, "expected an even number of items, found {len}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+impl Rucksack {
+    pub fn new(items: &str) -> Result<Self, RucksackError> {
+        if let Some((offset, item)) = items
+            .char_indices()
+            .find(|(_, item)| ItemSet::priority(*item).is_none())
+        {
+            return Err(RucksackError::NotAnItem { offset, item });
+        }
+        // Only ASCII is left, so bytes and chars agree.
+        if items.len() % 2 == 1 {
+            return Err(RucksackError::OddLength { len: items.len() });
+        }
+
+        let (left, right) = items.split_at(items.len() / 2);
+
+        Ok(Self {
+            items: items.to_string(),
+            left: left.chars().collect(),
+            right: right.chars().collect(),
+        })
+    }
+
+    pub fn items(&self) -> &str {
+        &self.items
+    }
+
+    pub fn compartments(&self) -> (&str, &str) {
+        self.items.split_at(self.items.len() / 2)
+    }
+
+    pub fn left(&self) -> ItemSet {
+        self.left
+    }
+
+    pub fn right(&self) -> ItemSet {
+        self.right
+    }
+
+    /// Every item type in the rucksack.
+    pub fn contents(&self) -> ItemSet {
+        self.left | self.right
+    }
+
+    /// The item types packed in both compartments.
+    pub fn misplaced(&self) -> ItemSet {
+        self.left & self.right
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compartments() {
+        let rucksack = Rucksack::new("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+
+        assert_eq!(rucksack.compartments(), ("vJrwpWtwJgWr", "hcsFMMfFFhFp"));
+        assert_eq!(rucksack.misplaced().iter().collect::<String>(), "p");
+        assert_eq!(rucksack.contents().len(), 14);
+    }
+
+    #[test]
+    fn test_invalid_rucksacks() {
+        assert_eq!(
+            Rucksack::new("ab1d"),
+            Err(RucksackError::NotAnItem {
+                offset: 2,
+                item: '1'
+            })
+        );
+        assert_eq!(
+            Rucksack::new("abé"),
+            Err(RucksackError::NotAnItem {
+                offset: 2,
+                item: 'é'
+            })
+        );
+        assert_eq!(
+            Rucksack::new("abc"),
+            Err(RucksackError::OddLength { len: 3 })
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+
+/// A non-empty, inclusive range of section numbers, like `2-4`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    start: usize,
+    end: usize,
+}
+
+// An interval always holds at least one section, so `is_empty` would
+// always be false.
+#[allow(clippy::len_without_is_empty)]
+impl Interval {
+    /// The sections from `start` to `end` inclusive, or `None` if `end`
+    /// comes before `start`.
+    pub fn new(start: usize, end: usize) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// How many sections the interval covers.
+    pub fn len(&self) -> usize {
+        self.end - self.start + 1
+    }
+
+    pub fn contains_section(&self, section: usize) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /// Whether every section of `other` is also in this interval.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn union(&self, other: &Self) -> IntervalSet {
+        [*self, *other].into_iter().collect()
+    }
+
+    /// The sections in this interval but not in `other`: none, one interval
+    /// or, when `other` is strictly inside, the two on either side of it.
+    pub fn difference(&self, other: &Self) -> IntervalSet {
+        if !self.overlaps(other) {
+            return IntervalSet::from(*self);
+        }
+
+        let before = other
+            .start
+            .checked_sub(1)
+            .and_then(|end| Self::new(self.start, end));
+        let after = other
+            .end
+            .checked_add(1)
+            .and_then(|start| Self::new(start, self.end));
+
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of sections, kept as sorted intervals that neither overlap nor
+/// touch: inserting `2-4` and `5-7` gives `2-7`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// How many sections are in the set.
+    pub fn len(&self) -> usize {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains_section(&self, section: usize) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains_section(section))
+    }
+
+    /// Adds `interval`, merging it with any intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        let mut merged = interval;
+        self.intervals.retain(|other| {
+            let touching = merged.start <= other.end.saturating_add(1)
+                && other.start <= merged.end.saturating_add(1);
+            if touching {
+                merged = Interval {
+                    start: merged.start.min(other.start),
+                    end: merged.end.max(other.end),
+                };
+            }
+            !touching
+        });
+
+        let idx = self
+            .intervals
+            .partition_point(|other| other.start < merged.start);
+        self.intervals.insert(idx, merged);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(|b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        other.intervals.iter().fold(self.clone(), |remaining, b| {
+            remaining
+                .intervals
+                .iter()
+                .flat_map(|a| a.difference(b).intervals)
+                .collect()
+        })
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let intervals: Vec<String> = self.intervals.iter().map(Interval::to_string).collect();
+        write!(f, "{}", intervals.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: usize, end: usize) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_interval_relations() {
+        assert!(interval(2, 8).contains(&interval(3, 7)));
+        assert!(!interval(3, 7).contains(&interval(2, 8)));
+        assert!(interval(5, 7).overlaps(&interval(7, 9)));
+        assert!(!interval(2, 3).overlaps(&interval(4, 5)));
+        assert_eq!(interval(6, 6).len(), 1);
+        assert_eq!(Interval::new(5, 3), None);
+    }
+
+    #[test]
+    fn test_interval_algebra() {
+        assert_eq!(
+            interval(2, 6).intersection(&interval(4, 8)),
+            Some(interval(4, 6))
+        );
+        assert_eq!(interval(2, 3).intersection(&interval(4, 5)), None);
+        assert_eq!(interval(2, 3).union(&interval(4, 5)).to_string(), "2-5");
+        assert_eq!(interval(2, 3).union(&interval(6, 7)).to_string(), "2-3,6-7");
+        assert_eq!(
+            interval(2, 8).difference(&interval(4, 5)).to_string(),
+            "2-3,6-8"
+        );
+        assert_eq!(
+            interval(0, 4).difference(&interval(0, 2)).to_string(),
+            "3-4"
+        );
+        assert!(interval(3, 7).difference(&interval(2, 8)).is_empty());
+    }
+
+    #[test]
+    fn test_set_merges_and_sorts() {
+        let set: IntervalSet = [
+            interval(10, 12),
+            interval(2, 4),
+            interval(5, 7),
+            interval(3, 11),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.intervals(), &[interval(2, 12)]);
+        assert_eq!(set.len(), 11);
+
+        let set: IntervalSet = [interval(8, 9), interval(1, 2), interval(4, 5)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.to_string(), "1-2,4-5,8-9");
+        assert!(set.contains_section(4) && !set.contains_section(3));
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let a: IntervalSet = [interval(1, 5), interval(10, 15)].into_iter().collect();
+        let b: IntervalSet = [interval(4, 11), interval(20, 20)].into_iter().collect();
+
+        assert_eq!(a.union(&b).to_string(), "1-15,20-20");
+        assert_eq!(a.intersection(&b).to_string(), "4-5,10-11");
+        assert_eq!(a.difference(&b).to_string(), "1-3,12-15");
+        assert_eq!(b.difference(&a).to_string(), "6-9,20-20");
+        assert!(a.difference(&a).is_empty());
+    }
+}
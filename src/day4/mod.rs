@@ -0,0 +1,202 @@
+pub mod interval;
+
+pub use interval::{Interval, IntervalSet};
+
+use crate::error::{non_blank_lines, ParseError};
+use crate::solution::Solution;
+
+/// The sections assigned to each elf of a pair.
+pub type AssignmentPair = (Interval, Interval);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<AssignmentPair>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        non_blank_lines(input)
+            .map(|(idx, line)| {
+                let (first, second) = line.split_once(',').ok_or_else(|| {
+                    ParseError::line(Self::DAY, idx, line, "expected two ranges like `2-4,6-8`")
+                })?;
+
+                Ok((
+                    parse_range(idx, line, first)?,
+                    parse_range(idx, line, second)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        count_full_containments(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        count_containments(input)
+    }
+}
+
+fn parse_range(idx: usize, line: &str, range: &str) -> Result<Interval, ParseError> {
+    let range = range.trim();
+    let (start, end) = range.split_once('-').ok_or_else(|| {
+        ParseError::at(Day4::DAY, idx, line, range, "expected a range like `2-4`")
+    })?;
+
+    let parse_section = |s: &str| {
+        let s = s.trim();
+        s.parse::<usize>()
+            .map_err(|_| ParseError::at(Day4::DAY, idx, line, s, "expected a section number"))
+    };
+
+    Interval::new(parse_section(start)?, parse_section(end)?).ok_or_else(|| {
+        ParseError::at(
+            Day4::DAY,
+            idx,
+            line,
+            range,
+            "expected the first section to come before the last",
+        )
+    })
+}
+
+/// Pairs where one elf's sections include all of the other's.
+pub fn count_full_containments(pairs: &[AssignmentPair]) -> usize {
+    pairs
+        .iter()
+        .filter(|(first, second)| first.contains(second) || second.contains(first))
+        .count()
+}
+
+/// Pairs whose sections overlap at all.
+pub fn count_containments(pairs: &[AssignmentPair]) -> usize {
+    pairs
+        .iter()
+        .filter(|(first, second)| first.overlaps(second))
+        .count()
+}
+
+/// Whether either range includes all of the other. A range that ends before
+/// it starts holds no sections, so it neither contains nor overlaps anything.
+pub fn check_full_containment(start_1: usize, end_1: usize, start_2: usize, end_2: usize) -> bool {
+    match (Interval::new(start_1, end_1), Interval::new(start_2, end_2)) {
+        (Some(first), Some(second)) => first.contains(&second) || second.contains(&first),
+        _ => false,
+    }
+}
+
+/// Whether the ranges share any section.
+pub fn check_partial_containment(
+    start_1: usize,
+    end_1: usize,
+    start_2: usize,
+    end_2: usize,
+) -> bool {
+    match (Interval::new(start_1, end_1), Interval::new(start_2, end_2)) {
+        (Some(first), Some(second)) => first.overlaps(&second),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_containment() {
+        assert!(check_partial_containment(2, 8, 3, 7));
+        assert!(check_partial_containment(3, 7, 2, 8));
+    }
+
+    #[test]
+    fn test_only_full_containment() {
+        assert!(check_full_containment(2, 8, 3, 7));
+        assert!(check_full_containment(6, 6, 4, 6));
+        assert!(!check_full_containment(2, 5, 3, 7));
+    }
+
+    #[test]
+    fn test_partial_containment() {
+        assert!(check_partial_containment(2, 5, 3, 7));
+        assert!(check_partial_containment(3, 7, 6, 8));
+    }
+
+    #[test]
+    fn test_no_containment() {
+        assert!(!check_partial_containment(2, 8, 10, 14));
+        assert!(!check_partial_containment(3, 7, 0, 2));
+    }
+
+    #[test]
+    fn test_backwards_ranges_contain_nothing() {
+        assert!(!check_full_containment(8, 2, 3, 7));
+        assert!(!check_full_containment(2, 8, 7, 3));
+        assert!(!check_partial_containment(5, 3, 4, 4));
+    }
+
+    #[test]
+    fn test_example_data() {
+        const DATA: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+        let actual = count_containments(&Day4::parse(DATA).unwrap());
+        const EXPECTED: usize = 4;
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_example_full_containments() {
+        const DATA: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+        let actual = count_full_containments(&Day4::parse(DATA).unwrap());
+        const EXPECTED: usize = 2;
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_different_data() {
+        const DATA: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+4-4, 2-7
+6-6,4-6
+2-6,4-8";
+
+        let actual = count_containments(&Day4::parse(DATA).unwrap());
+        const EXPECTED: usize = 5;
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_bad_section_is_located() {
+        let err = Day4::parse("2-4,6-8\n2-3,4-z5").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.text, "z5");
+    }
+
+    #[test]
+    fn test_backwards_range_is_located() {
+        let err = Day4::parse("2-4,6-8\n5-3,4-5").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "5-3");
+    }
+}
@@ -0,0 +1,374 @@
+use crate::error::{non_blank_lines, ParseError};
+use crate::solution::Solution;
+use crate::{debug, trace};
+
+#[derive(Debug)]
+pub struct Procedure {
+    pub crates: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type PartOne = Result<String, String>;
+    type PartTwo = Result<String, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (crate_text, instruction_text) = split_crates_and_instructions(input)?;
+
+        let crates = extract_initial_crates(crate_text)?;
+
+        debug!(Self::DAY, "Starting with crates: {crates:?}");
+
+        let instruction_offset = crate_text.lines().count() + 1;
+        let instructions = parse_instructions(instruction_text)
+            .map_err(|err| err.offset_lines(instruction_offset))?;
+
+        for ((idx, line), instruction) in non_blank_lines(instruction_text).zip(&instructions) {
+            if instruction.from >= crates.len() || instruction.to >= crates.len() {
+                return Err(ParseError::line(
+                    Self::DAY,
+                    instruction_offset + idx,
+                    line,
+                    format!("there are only {} columns of crates", crates.len()),
+                ));
+            }
+        }
+
+        trace!(Self::DAY, "Following instructions: {instructions:?}");
+
+        Ok(Procedure {
+            crates,
+            instructions,
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let final_crates = apply_instructions_one_at_a_time(&input.crates, &input.instructions)?;
+
+        Ok(top_of_each_column(&final_crates))
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let final_crates = apply_instructions(&input.crates, &input.instructions)?;
+
+        debug!(Self::DAY, "The final crates are: {final_crates:?}");
+
+        Ok(top_of_each_column(&final_crates))
+    }
+}
+
+/// The crate on top of each column. Empty columns have no top crate and are
+/// left out.
+pub fn top_of_each_column(crates: &[Vec<char>]) -> String {
+    crates.iter().filter_map(|v| v.last()).collect()
+}
+
+pub fn split_crates_and_instructions(input: &str) -> Result<(&str, &str), ParseError> {
+    input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(
+            Day5::DAY,
+            input.lines().count(),
+            "",
+            "",
+            "could not find the empty line between the crates and the instructions",
+        )
+    })
+}
+
+pub fn extract_initial_crates(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines: Vec<&str> = input.split('\n').collect();
+    let last_idx = lines.len() - 1;
+    let numbers = lines[last_idx];
+    let width: usize = numbers
+        .rmatches(char::is_numeric)
+        .next()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| {
+            ParseError::line(
+                Day5::DAY,
+                last_idx,
+                numbers,
+                "expected a row of column numbers",
+            )
+        })?;
+
+    debug!(Day5::DAY, "Crates have a width of {width}");
+
+    let mut result = Vec::new();
+    result.resize(width, Vec::new());
+
+    lines.iter().rev().skip(1).for_each(|s| {
+        (0..width)
+            .map(|i| 1 + (i * 4))
+            .enumerate()
+            .for_each(|(column, i)| {
+                if let Some(s) = s.get(i..i + 1) {
+                    let c = s.chars().next().unwrap();
+                    if c.is_alphabetic() {
+                        result[column].push(c);
+                    }
+                }
+            })
+    });
+
+    Ok(result)
+}
+
+/// Moves `quantity` crates between two zero-indexed columns.
+#[derive(Debug, PartialEq)]
+pub struct Instruction {
+    pub quantity: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    non_blank_lines(input)
+        .map(|(idx, line)| {
+            trace!(Day5::DAY, "Parsing instruction: {line}");
+            parse_instruction(line).map_err(|err| err.offset_lines(idx))
+        })
+        .collect()
+}
+
+/// Parses a single `move 1 from 2 to 3` line. Errors are reported as if the
+/// line were the first of the input.
+pub fn parse_instruction(line: &str) -> Result<Instruction, ParseError> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let ["move", quantity, "from", from, "to", to] = words[..] else {
+        return Err(ParseError::line(
+            Day5::DAY,
+            0,
+            line,
+            "expected an instruction like `move 1 from 2 to 3`",
+        ));
+    };
+
+    let parse_num = |s: &str| {
+        s.parse::<usize>()
+            .map_err(|_| ParseError::at(Day5::DAY, 0, line, s, "expected a number"))
+    };
+    let parse_column = |s: &str| match parse_num(s)? {
+        0 => Err(ParseError::at(Day5::DAY, 0, line, s, "columns start at 1")),
+        column => Ok(column - 1),
+    };
+
+    Ok(Instruction {
+        quantity: parse_num(quantity)?,
+        from: parse_column(from)?,
+        to: parse_column(to)?,
+    })
+}
+
+fn too_few_crates(idx: usize, instruction: &Instruction, crates: usize) -> String {
+    format!(
+        "instruction {} moves {} crates from column {}, which only has {crates}",
+        idx + 1,
+        instruction.quantity,
+        instruction.from + 1
+    )
+}
+
+/// Moves crates the way the CrateMover 9000 does, one crate at a time, so a
+/// moved stack ends up reversed. Fails if an instruction takes more crates
+/// than its column has.
+pub fn apply_instructions_one_at_a_time(
+    initial_crates: &[Vec<char>],
+    instructions: &[Instruction],
+) -> Result<Vec<Vec<char>>, String> {
+    let mut result = initial_crates.to_vec();
+    for (idx, instruction) in instructions.iter().enumerate() {
+        for moved in 0..instruction.quantity {
+            let c = result[instruction.from]
+                .pop()
+                .ok_or_else(|| too_few_crates(idx, instruction, moved))?;
+            result[instruction.to].push(c);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Moves crates the way the CrateMover 9001 does, picking up the whole stack
+/// at once so it keeps its order. Fails if an instruction takes more crates
+/// than its column has.
+pub fn apply_instructions(
+    initial_crates: &[Vec<char>],
+    instructions: &[Instruction],
+) -> Result<Vec<Vec<char>>, String> {
+    let mut result = initial_crates.to_vec();
+    for (idx, instruction) in instructions.iter().enumerate() {
+        let from_column = instruction.from;
+        let to_column = instruction.to;
+
+        let crates = result[from_column].len();
+        let from_idx = crates
+            .checked_sub(instruction.quantity)
+            .ok_or_else(|| too_few_crates(idx, instruction, crates))?;
+        let mut slice = result[from_column].split_off(from_idx);
+
+        result[to_column].append(&mut slice);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_DATA: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    fn example_initial_crates() -> Vec<Vec<char>> {
+        vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+    }
+
+    fn example_final_crates() -> Vec<Vec<char>> {
+        vec![vec!['M'], vec!['C'], vec!['P', 'Z', 'N', 'D']]
+    }
+
+    fn example_instructions() -> Vec<Instruction> {
+        vec![
+            Instruction {
+                quantity: 1,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                quantity: 3,
+                from: 0,
+                to: 2,
+            },
+            Instruction {
+                quantity: 2,
+                from: 1,
+                to: 0,
+            },
+            Instruction {
+                quantity: 1,
+                from: 0,
+                to: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_extract_example_initial_crates() {
+        let expected: Vec<Vec<char>> = example_initial_crates();
+
+        let (crates, _) = split_crates_and_instructions(EXAMPLE_DATA).unwrap();
+        let actual = extract_initial_crates(crates).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_extract_example_instructions() {
+        let expected = example_instructions();
+        let (_, instructions) = split_crates_and_instructions(EXAMPLE_DATA).unwrap();
+
+        let actual = parse_instructions(instructions).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_apply_example_instructions() {
+        let expected = example_final_crates();
+        let actual =
+            apply_instructions(&example_initial_crates(), &example_instructions()).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_apply_example_instructions_one_at_a_time() {
+        let expected = vec![vec!['C'], vec!['M'], vec!['P', 'D', 'N', 'Z']];
+        let actual =
+            apply_instructions_one_at_a_time(&example_initial_crates(), &example_instructions())
+                .unwrap();
+
+        assert_eq!(actual, expected);
+        assert_eq!(top_of_each_column(&actual), "CMZ");
+    }
+
+    #[test]
+    fn test_moving_too_many_crates_one_at_a_time() {
+        let instructions = [Instruction {
+            quantity: 3,
+            from: 0,
+            to: 1,
+        }];
+
+        assert_eq!(
+            apply_instructions_one_at_a_time(&example_initial_crates(), &instructions),
+            Err("instruction 1 moves 3 crates from column 1, which only has 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_moving_too_many_crates_at_once() {
+        let data = EXAMPLE_DATA.replace("move 1 from 2 to 1", "move 4 from 2 to 1");
+        let procedure = Day5::parse(&data).unwrap();
+
+        assert_eq!(
+            Day5::part_two(&procedure),
+            Err("instruction 1 moves 4 crates from column 2, which only has 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_empty_columns_have_no_top() {
+        assert_eq!(top_of_each_column(&[vec!['A'], vec![], vec!['B']]), "AB");
+    }
+
+    #[test]
+    fn test_can_parse_multidigit_columns_in_instructions() {
+        let line = "move 10 from 6 to 9";
+        let actual = parse_instruction(line).unwrap();
+        let expected = Instruction {
+            quantity: 10,
+            from: 5,
+            to: 8,
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_bad_instruction_is_located() {
+        let data = EXAMPLE_DATA.replace("move 2 from 2", "move 2 from two");
+        let err = Day5::parse(&data).unwrap_err();
+
+        assert_eq!((err.line, err.column), (8, 13));
+        assert_eq!(err.text, "two");
+    }
+
+    #[test]
+    fn test_instruction_column_out_of_range() {
+        let data = EXAMPLE_DATA.replace("move 1 from 1 to 2", "move 1 from 1 to 4");
+        let err = Day5::parse(&data).unwrap_err();
+
+        assert_eq!(err.line, 9);
+    }
+
+    #[test]
+    fn test_missing_instructions() {
+        let err = Day5::parse("[A]\n 1 ").unwrap_err();
+
+        assert_eq!(err.line, 3);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::debug;
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub const PACKET_MARKER_LENGTH: usize = 4;
+pub const MESSAGE_MARKER_LENGTH: usize = 14;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type PartOne = Result<usize, String>;
+    type PartTwo = Result<usize, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.trim();
+
+        // The marker search slices by byte, so only ASCII is supported.
+        if let Some((idx, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_graphic()) {
+            let text = &line[idx..idx + c.len_utf8()];
+            return Err(ParseError::at(
+                Self::DAY,
+                0,
+                line,
+                text,
+                "expected a single line of ASCII characters",
+            ));
+        }
+
+        Ok(line.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        find_marker_idx(input, PACKET_MARKER_LENGTH)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        find_marker_idx(input, MESSAGE_MARKER_LENGTH)
+    }
+}
+
+/// The number of characters read up to the end of the first run of
+/// `marker_length` different characters, or an error if there is none.
+pub fn find_marker_idx(input: &str, marker_length: usize) -> Result<usize, String> {
+    let length_to_test = marker_length - 1;
+    for idx in 0..(input.len() + 1).saturating_sub(marker_length) {
+        let sub = &input[idx..idx + length_to_test];
+        let last = &input[idx + length_to_test..idx + length_to_test + 1];
+
+        let final_char_in_substr = sub.contains(last);
+        let sub_contains_duplicates = string_contains_duplicates(sub);
+        if !(sub_contains_duplicates || final_char_in_substr) {
+            debug!(Day6::DAY, "Returning index of {sub} with {last}");
+            return Ok(idx + length_to_test + 1);
+        }
+    }
+
+    Err(format!(
+        "no marker found: no {marker_length} characters in a row are all different"
+    ))
+}
+
+pub fn string_contains_duplicates(input: &str) -> bool {
+    let mut chars: Vec<char> = input.chars().collect();
+    chars.sort();
+    chars.dedup();
+
+    chars.len() != input.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: [(&str, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+    ];
+
+    const PACKET_EXAMPLES: [(&str, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+    ];
+
+    #[test]
+    fn test_packet_examples() {
+        PACKET_EXAMPLES.iter().for_each(|(input, expected)| {
+            let actual = find_marker_idx(input, PACKET_MARKER_LENGTH);
+            assert_eq!(actual, Ok(*expected), "Testing string {input}.");
+        });
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(
+            find_marker_idx("abcabcabc", PACKET_MARKER_LENGTH),
+            Err("no marker found: no 4 characters in a row are all different".to_string())
+        );
+
+        let input = Day6::parse("abcd").unwrap();
+        assert_eq!(Day6::part_one(&input), Ok(4));
+        assert!(Day6::part_two(&input).is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        EXAMPLES.iter().for_each(|(input, expected)| {
+            let actual = find_marker_idx(input, MESSAGE_MARKER_LENGTH);
+            assert_eq!(
+                actual,
+                Ok(*expected),
+                "Testing string {input}. Expecting answer to be: {}",
+                &input[expected - 14..*expected]
+            );
+        });
+    }
+
+    #[test]
+    fn test_non_ascii_is_located() {
+        let err = Day6::parse("abcdé fgh").unwrap_err();
+
+        assert_eq!(err.column, 5);
+        assert_eq!(err.text, "é");
+    }
+}
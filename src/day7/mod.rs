@@ -0,0 +1,410 @@
+use crate::error::{non_blank_lines, ParseError};
+use crate::solution::Solution;
+use crate::{debug, trace};
+
+pub const THRESHOLD: usize = 100000;
+pub const DISK_SIZE: usize = 70000000;
+pub const SPACE_NEEDED_FOR_UPDATE: usize = 30000000;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<String>;
+    type PartOne = Result<usize, String>;
+    type PartTwo = Result<usize, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        non_blank_lines(input)
+            .enumerate()
+            .map(|(position, (idx, line))| {
+                let trimmed = line.trim();
+                if position == 0 && trimmed != "$ cd /" {
+                    return Err(ParseError::line(
+                        Self::DAY,
+                        idx,
+                        line,
+                        "expected the transcript to start with `$ cd /`",
+                    ));
+                }
+
+                check_terminal_line(idx, line)?;
+                Ok(trimmed.to_string())
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        let lines: Vec<&str> = input.iter().map(String::as_str).collect();
+        total_dirs_under_threshold(&lines, THRESHOLD)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        let lines: Vec<&str> = input.iter().map(String::as_str).collect();
+        smallest_dir_to_free(&lines)
+    }
+}
+
+/// Makes sure a line is a command or `ls` output the solvers understand.
+fn check_terminal_line(idx: usize, line: &str) -> Result<(), ParseError> {
+    let trimmed = line.trim();
+    if trimmed == "$ ls" || trimmed.starts_with("$ cd ") || trimmed.starts_with("dir ") {
+        return Ok(());
+    }
+
+    let mut words = trimmed.split_whitespace();
+    match (words.next(), words.next()) {
+        (Some(size), Some(_)) if size.starts_with(|c: char| c.is_ascii_digit()) => {
+            match size.parse::<usize>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err(ParseError::at(
+                    Day7::DAY,
+                    idx,
+                    line,
+                    size,
+                    "expected a file size",
+                )),
+            }
+        }
+        _ => Err(ParseError::line(
+            Day7::DAY,
+            idx,
+            line,
+            "expected `$ cd`, `$ ls`, `dir <name>` or `<size> <name>`",
+        )),
+    }
+}
+
+pub fn split_input_to_lines(input: &str) -> Vec<&str> {
+    input.trim().split("\n").collect()
+}
+
+struct DirSizeInfo {
+    running_total_size: usize,
+    children_under_threshold_total_size: usize,
+}
+
+impl DirSizeInfo {
+    fn zero() -> Self {
+        Self {
+            running_total_size: 0,
+            children_under_threshold_total_size: 0,
+        }
+    }
+}
+
+fn add_size(total: usize, size: usize) -> Result<usize, String> {
+    total
+        .checked_add(size)
+        .ok_or_else(|| "the directory sizes are too large to add up".to_string())
+}
+
+/// The size of the directory listed from `start_idx` and of every directory
+/// inside it, smallest first. Fails if a size overflows.
+pub fn flat_dir_sizes_recursive(lines: &Vec<&str>, start_idx: usize) -> Result<Vec<usize>, String> {
+    let mut result = Vec::new();
+    let mut nesting_level = 1;
+    let mut my_size: usize = 0;
+
+    for idx in start_idx..lines.len() {
+        let line = lines[idx].trim();
+
+        if line == "$ cd .." {
+            nesting_level -= 1;
+
+            if nesting_level == 0 {
+                break;
+            } else {
+                continue;
+            }
+        }
+
+        if line.chars().next().unwrap().is_numeric() {
+            if nesting_level == 1 {
+                let size = line
+                    .split_whitespace()
+                    .next()
+                    .unwrap()
+                    .parse::<usize>()
+                    .unwrap();
+                my_size = add_size(my_size, size)?;
+            }
+        } else if line.starts_with("$ cd") {
+            if nesting_level == 1 {
+                let mut child_dir = flat_dir_sizes_recursive(lines, idx + 1)?;
+
+                my_size = add_size(my_size, *child_dir.last().unwrap())?;
+
+                result.append(&mut child_dir);
+            }
+
+            nesting_level += 1;
+        }
+    }
+
+    result.push(my_size);
+    result.sort();
+    Ok(result)
+}
+
+/// The size of the smallest directory that frees up enough space for the
+/// update, or an error if the files don't fit on the disk or there's
+/// already enough space free.
+pub fn smallest_dir_to_free(lines: &Vec<&str>) -> Result<usize, String> {
+    let flat_dirs = flat_dir_sizes_recursive(lines, 1)?;
+    let total_used_space = *flat_dirs.last().unwrap();
+    let remaining_space = DISK_SIZE.checked_sub(total_used_space).ok_or_else(|| {
+        format!("the files take up {total_used_space}, more than the disk's {DISK_SIZE}")
+    })?;
+    let needed_space = SPACE_NEEDED_FOR_UPDATE
+        .checked_sub(remaining_space)
+        .filter(|needed| *needed > 0)
+        .ok_or_else(|| {
+            format!("{remaining_space} is already free, enough for the update without deleting anything")
+        })?;
+
+    debug!(Day7::DAY, "Total used space: {total_used_space}");
+    debug!(Day7::DAY, "Remaining space on disk: {remaining_space}");
+    debug!(Day7::DAY, "I need at least {needed_space} to be freed");
+
+    flat_dirs
+        .iter()
+        .find(|size| **size >= needed_space)
+        .copied()
+        .ok_or_else(|| format!("no directory is {needed_space} or bigger"))
+}
+
+pub fn total_dirs_under_threshold(lines: &Vec<&str>, threshold: usize) -> Result<usize, String> {
+    let final_result = recursive_size_and_total_under_threshold(lines, threshold, 1)?;
+
+    Ok(final_result.children_under_threshold_total_size)
+}
+
+fn recursive_size_and_total_under_threshold(
+    lines: &Vec<&str>,
+    threshold: usize,
+    start_idx: usize,
+) -> Result<DirSizeInfo, String> {
+    trace!(
+        Day7::DAY,
+        "Starting check for dir beginning at {start_idx} -- {}",
+        lines.get(start_idx).unwrap_or(&"")
+    );
+    let mut result = DirSizeInfo::zero();
+    let mut nesting_level = 1;
+
+    for idx in start_idx..lines.len() {
+        let line = lines[idx].trim();
+
+        if line == "$ cd .." {
+            nesting_level -= 1;
+
+            if nesting_level == 0 {
+                break;
+            } else {
+                continue;
+            }
+        }
+
+        if line.chars().next().unwrap().is_numeric() {
+            if nesting_level == 1 {
+                let size = line
+                    .split_whitespace()
+                    .next()
+                    .unwrap()
+                    .parse::<usize>()
+                    .unwrap();
+                result.running_total_size = add_size(result.running_total_size, size)?;
+            }
+        } else if line.starts_with("$ cd") {
+            if nesting_level == 1 {
+                trace!(Day7::DAY, "Entering dir: {line}");
+                let child_dir =
+                    recursive_size_and_total_under_threshold(lines, threshold, idx + 1)?;
+                result.running_total_size =
+                    add_size(result.running_total_size, child_dir.running_total_size)?;
+                result.children_under_threshold_total_size = add_size(
+                    result.children_under_threshold_total_size,
+                    child_dir.children_under_threshold_total_size,
+                )?;
+            }
+
+            nesting_level += 1;
+        }
+    }
+
+    trace!(
+        Day7::DAY,
+        "Size of dir starting at line {start_idx} is {}",
+        result.running_total_size
+    );
+    if result.running_total_size <= threshold {
+        result.children_under_threshold_total_size = add_size(
+            result.children_under_threshold_total_size,
+            result.running_total_size,
+        )?;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    const SINGLE_DIR_EXAMPLE: &str = "$ cd /
+$ ls
+200 b.txt
+400 c.dat
+";
+
+    const NESTING_DIRS_EXAMPLE: &str = "$ cd /
+$ ls
+dir h
+200 b.txt
+400 c.dat
+$ cd dir h
+$ ls
+500 r.dangit
+1000 b.dangit
+";
+
+    #[test]
+    fn test_single_dir() {
+        let lines = split_input_to_lines(SINGLE_DIR_EXAMPLE);
+        let actual = total_dirs_under_threshold(&lines, THRESHOLD);
+        let expected = 600;
+
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_example() {
+        let lines = split_input_to_lines(EXAMPLE_INPUT);
+        let total = total_dirs_under_threshold(&lines, 100000);
+        let expected = 95437;
+
+        assert_eq!(total, Ok(expected));
+    }
+
+    #[test]
+    fn test_smallest_dir_to_free_example() {
+        let lines = split_input_to_lines(EXAMPLE_INPUT);
+        let actual = smallest_dir_to_free(&lines);
+        let expected = 24933642;
+
+        assert_eq!(actual, Ok(expected));
+    }
+
+    #[test]
+    fn test_nothing_to_free() {
+        let lines = Day7::parse("$ cd /\n$ ls\n100 a").unwrap();
+
+        assert_eq!(Day7::part_one(&lines), Ok(100));
+        assert_eq!(
+            Day7::part_two(&lines),
+            Err(
+                "69999900 is already free, enough for the update without deleting anything"
+                    .to_string()
+            )
+        );
+
+        let lines = Day7::parse("$ cd /").unwrap();
+        assert_eq!(Day7::part_one(&lines), Ok(0));
+        assert!(Day7::part_two(&lines).is_err());
+
+        let lines = Day7::parse("$ cd /\n$ ls\n70000001 a").unwrap();
+        assert_eq!(
+            Day7::part_two(&lines),
+            Err("the files take up 70000001, more than the disk's 70000000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_flat_dir_single() {
+        let lines = split_input_to_lines(SINGLE_DIR_EXAMPLE);
+        let flat_single_dir = flat_dir_sizes_recursive(&lines, 1).unwrap();
+        let expected = vec![600];
+
+        assert_eq!(flat_single_dir, expected);
+    }
+
+    #[test]
+    fn test_flat_dir_example() {
+        let lines = split_input_to_lines(EXAMPLE_INPUT);
+        let flat_example = flat_dir_sizes_recursive(&lines, 1).unwrap();
+
+        let expected = vec![584, 94853, 24933642, 48381165];
+
+        assert_eq!(flat_example, expected);
+    }
+
+    #[test]
+    fn test_flat_dir_nesting() {
+        let lines = split_input_to_lines(NESTING_DIRS_EXAMPLE);
+        let actual = flat_dir_sizes_recursive(&lines, 1).unwrap();
+
+        let expected = vec![1500, 2100];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_sizes_too_large_to_add_up() {
+        let lines = Day7::parse("$ cd /\n$ ls\n18446744073709551615 a\n1 b").unwrap();
+        let err = "the directory sizes are too large to add up".to_string();
+
+        assert_eq!(Day7::part_one(&lines), Err(err.clone()));
+        assert_eq!(Day7::part_two(&lines), Err(err));
+    }
+
+    #[test]
+    fn test_parse_keeps_transcript() {
+        let lines = Day7::parse(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(lines, split_input_to_lines(EXAMPLE_INPUT));
+    }
+
+    #[test]
+    fn test_bad_file_size_is_located() {
+        let input = EXAMPLE_INPUT.replace("2557 g", "25x7 g");
+        let err = Day7::parse(&input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (11, 1));
+        assert_eq!(err.text, "25x7");
+    }
+
+    #[test]
+    fn test_unknown_command_is_located() {
+        let input = EXAMPLE_INPUT.replace("$ cd e", "$ rm -rf e");
+        let err = Day7::parse(&input).unwrap_err();
+
+        assert_eq!(err.line, 13);
+        assert_eq!(err.text, "$ rm -rf e");
+    }
+}
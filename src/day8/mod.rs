@@ -0,0 +1,141 @@
+use crate::error::ParseError;
+use crate::grid::{Direction, Grid, Pos};
+use crate::solution::Solution;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<u32>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_height_array(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        count_visible_trees(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        find_high_scenic_score(input)
+    }
+}
+
+pub fn build_height_array(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(Day8::DAY, input, "tree", |c| c.to_digit(10))
+}
+
+/// A tree is visible if every tree between it and an edge, in at least one
+/// direction, is shorter than it. Trees on the edge are always visible.
+pub fn build_visibility_array(height_array: &Grid<u32>) -> Grid<bool> {
+    let mut vis_array = Grid::new(height_array.width(), height_array.height(), false);
+
+    for (pos, height) in height_array.iter() {
+        vis_array[pos] = Direction::ALL.into_iter().any(|direction| {
+            height_array
+                .ray(pos, direction)
+                .all(|other| height_array[other] < *height)
+        });
+    }
+
+    vis_array
+}
+
+pub fn count_visible_trees(height_array: &Grid<u32>) -> usize {
+    let vis_array = build_visibility_array(height_array);
+
+    vis_array.iter().filter(|(_, visible)| **visible).count()
+}
+
+/// How many trees can be seen from `pos` looking in `direction`: up to and
+/// including the first one at least as tall, or to the edge.
+fn viewing_distance(height_array: &Grid<u32>, pos: Pos, direction: Direction) -> usize {
+    let tree_height = height_array[pos];
+    let mut distance = 0;
+
+    for other in height_array.ray(pos, direction) {
+        distance += 1;
+        if height_array[other] >= tree_height {
+            break;
+        }
+    }
+
+    distance
+}
+
+pub fn find_high_scenic_score(height_array: &Grid<u32>) -> usize {
+    height_array
+        .positions()
+        .map(|pos| {
+            Direction::ALL
+                .into_iter()
+                .map(|direction| viewing_distance(height_array, pos, direction))
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "30373
+25512
+65332
+33549
+35390";
+
+    const ALL_BORDER: &str = "1234
+        5678";
+
+    #[test]
+    fn test_example() {
+        const EXPECTED: usize = 21;
+        let actual = count_visible_trees(&Day8::parse(EXAMPLE).unwrap());
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_all_borders() {
+        const EXPECTED: usize = 8;
+        let actual = count_visible_trees(&Day8::parse(ALL_BORDER).unwrap());
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_scenic_example() {
+        const EXPECTED: usize = 8;
+        let actual = find_high_scenic_score(&Day8::parse(EXAMPLE).unwrap());
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_border_scenicness() {
+        const EXPECTED: usize = 0;
+        let actual = find_high_scenic_score(&Day8::parse(ALL_BORDER).unwrap());
+
+        assert_eq!(actual, EXPECTED);
+    }
+
+    #[test]
+    fn test_bad_height_is_located() {
+        let err = Day8::parse("30373\n255.2\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let err = Day8::parse("30373\n2551\n").unwrap_err();
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.reason, "expected a row of 5 trees");
+    }
+}
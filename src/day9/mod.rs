@@ -0,0 +1,324 @@
+use crate::debug;
+use crate::error::{non_blank_lines, ParseError};
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+pub const ROPE_KNOTS: usize = 10;
+
+/// Every step of the head is kept, so the total number of steps is capped.
+pub const MAX_STEPS: usize = 1_000_000;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(i32, i32)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_head_position_list(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        count_distinct_locations_visited(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        count_multi_joint_tail_positions_visited(input, ROPE_KNOTS)
+    }
+}
+
+pub fn count_distinct_locations_visited(head_positions: &[(i32, i32)]) -> usize {
+    let mut visited_coords = build_tail_pos_from_head(head_positions);
+
+    visited_coords.sort();
+    visited_coords.dedup();
+
+    visited_coords.len()
+}
+
+pub fn build_head_position_list(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    let mut head_pos = (0, 0);
+    let mut visited_coords = vec![(0, 0)];
+
+    for (idx, line) in non_blank_lines(input) {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let [dir, count] = words[..] else {
+            return Err(ParseError::line(
+                Day9::DAY,
+                idx,
+                line,
+                "expected a motion like `R 4`",
+            ));
+        };
+
+        let steps = count.parse::<usize>().map_err(|_| {
+            ParseError::at(Day9::DAY, idx, line, count, "expected a number of steps")
+        })?;
+        if steps > MAX_STEPS + 1 - visited_coords.len() {
+            return Err(ParseError::at(
+                Day9::DAY,
+                idx,
+                line,
+                count,
+                format!("the motions take more than {MAX_STEPS} steps"),
+            ));
+        }
+
+        let delta = match dir {
+            "U" => (0, -1),
+            "D" => (0, 1),
+            "R" => (1, 0),
+            "L" => (-1, 0),
+            _ => {
+                return Err(ParseError::at(
+                    Day9::DAY,
+                    idx,
+                    line,
+                    dir,
+                    "expected U, D, L or R",
+                ))
+            }
+        };
+
+        (0..steps).for_each(|_| {
+            head_pos.0 += delta.0;
+            head_pos.1 += delta.1;
+            visited_coords.push(head_pos);
+        });
+    }
+
+    Ok(visited_coords)
+}
+
+pub fn build_tail_pos_from_head(head: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut tail_pos = (0, 0);
+    let mut visited_coords = vec![];
+
+    for head_pos in head {
+        let x_diff: i32 = head_pos.0 - tail_pos.0;
+        let y_diff: i32 = head_pos.1 - tail_pos.1;
+        if x_diff.abs() > 1 {
+            let dir = x_diff.signum();
+            tail_pos.0 += dir;
+
+            let y_dir = y_diff.signum();
+            tail_pos.1 += y_dir;
+        } else if y_diff.abs() > 1 {
+            let dir = y_diff.signum();
+            tail_pos.1 += dir;
+
+            let x_dir = x_diff.signum();
+            tail_pos.0 += x_dir;
+        }
+
+        visited_coords.push(tail_pos);
+    }
+
+    visited_coords
+}
+
+pub fn count_multi_joint_tail_positions_visited(
+    head_positions: &[(i32, i32)],
+    tail_segments: usize,
+) -> usize {
+    let mut furthest_tail = head_positions.to_vec();
+    (1..tail_segments).for_each(|i| {
+        debug!(Day9::DAY, "Building tail {i}");
+        let new_tail = build_tail_pos_from_head(&furthest_tail);
+        furthest_tail = new_tail;
+    });
+
+    furthest_tail.sort();
+    debug!(Day9::DAY, "Length before dedup: {}", furthest_tail.len());
+    furthest_tail.dedup();
+    furthest_tail.len()
+}
+
+/// Draws the rope after every step of the head, one frame per step. Frames
+/// are just big enough to hold every position the rope ever reaches.
+pub fn visualize_multi_jointed_tail(
+    head_positions: &[(i32, i32)],
+    tail_segments: usize,
+) -> Vec<String> {
+    let mut vis_positions = vec![head_positions.to_vec()];
+
+    let mut furthest_tail = head_positions.to_vec();
+    (1..tail_segments).for_each(|_| {
+        let new_tail = build_tail_pos_from_head(&furthest_tail);
+        furthest_tail = new_tail;
+        vis_positions.push(furthest_tail.clone());
+    });
+
+    let all_positions = || vis_positions.iter().flatten();
+    let (Some(lowest_x), Some(highest_x)) = (
+        all_positions().map(|(x, _)| *x).min(),
+        all_positions().map(|(x, _)| *x).max(),
+    ) else {
+        return Vec::new();
+    };
+    let lowest_y = all_positions().map(|(_, y)| *y).min().unwrap();
+    let highest_y = all_positions().map(|(_, y)| *y).max().unwrap();
+
+    let width = (highest_x - lowest_x + 1) as usize;
+    let height = (highest_y - lowest_y + 1) as usize;
+
+    (0..head_positions.len())
+        .map(|idx| {
+            let mut grid = Grid::new(width, height, '.');
+
+            vis_positions
+                .iter()
+                .enumerate()
+                .rev()
+                .for_each(|(segment_idx, points)| {
+                    let c = if segment_idx == 0 {
+                        'H'
+                    } else {
+                        char::from_digit(segment_idx as u32, 36).unwrap_or('#')
+                    };
+
+                    let (x, y) = points[idx];
+                    grid[((x - lowest_x) as usize, (y - lowest_y) as usize)] = c;
+                });
+
+            grid.render(|c| *c)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    #[test]
+    fn test_example() {
+        let actual = count_distinct_locations_visited(&Day9::parse(EXAMPLE).unwrap());
+        let expected = 13;
+
+        assert_eq!(actual, expected);
+    }
+
+    const VISIT_ONE_MORE: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+U 2
+";
+
+    #[test]
+    fn test_visit_one_more() {
+        let actual = count_distinct_locations_visited(&Day9::parse(VISIT_ONE_MORE).unwrap());
+        let expected = 14;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_multi_joint_tail_initial_example() {
+        let actual = count_multi_joint_tail_positions_visited(&Day9::parse(EXAMPLE).unwrap(), 10);
+        let expected = 1;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_multi_joint_different_lengths() {
+        let actual = count_multi_joint_tail_positions_visited(&Day9::parse(EXAMPLE).unwrap(), 4);
+        let expected = 4;
+        assert_eq!(actual, expected);
+    }
+
+    const LARGE_EXAMPLE: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn test_large_multi_jointed_tail_example() {
+        let actual =
+            count_multi_joint_tail_positions_visited(&Day9::parse(LARGE_EXAMPLE).unwrap(), 10);
+        let expected = 36;
+
+        assert_eq!(actual, expected);
+    }
+
+    const DOUBLE_BACK: &str = "R 20
+        L 20";
+
+    #[test]
+    fn test_double_back() {
+        let actual =
+            count_multi_joint_tail_positions_visited(&Day9::parse(DOUBLE_BACK).unwrap(), 10);
+        let expected = 12;
+
+        assert_eq!(actual, expected);
+    }
+
+    const UNMOVING_SIXTH: &str = "R 4
+        U 4";
+
+    #[test]
+    fn test_unmoving_6th_segment_edge_case() {
+        let actual =
+            count_multi_joint_tail_positions_visited(&Day9::parse(UNMOVING_SIXTH).unwrap(), 7);
+        let expected = 1;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_visualization() {
+        let frames = visualize_multi_jointed_tail(&Day9::parse(EXAMPLE).unwrap(), 10);
+
+        assert_eq!(frames.len(), 25);
+        assert_eq!(frames[0], "......\n......\n......\n......\nH.....");
+        assert_eq!(frames[2], "......\n......\n......\n......\n21H...");
+    }
+
+    #[test]
+    fn test_bad_direction_is_located() {
+        let err = Day9::parse("R 4\nU 4\nX 3\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.text, "X");
+    }
+
+    #[test]
+    fn test_bad_step_count_is_located() {
+        let err = Day9::parse("R 4\n  U -4\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+
+    #[test]
+    fn test_too_many_steps_is_rejected() {
+        let err = Day9::parse("R 4\nR 3000000000\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert!(err.to_string().contains("more than 1000000 steps"));
+
+        let steps = format!("R {MAX_STEPS}");
+        assert_eq!(Day9::parse(&steps).unwrap().len(), MAX_STEPS + 1);
+        assert!(Day9::parse(&format!("{steps}\nL 1")).is_err());
+    }
+}
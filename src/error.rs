@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// A problem with a puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    /// One-based line number in the input.
+    pub line: usize,
+    /// One-based column (in chars) where the offending text starts.
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Builds an error for `text`, which must be a slice of `line` so its
+    /// column can be worked out. `line_idx` is zero-based, as handed out by
+    /// `enumerate`.
+    pub fn at(day: u8, line_idx: usize, line: &str, text: &str, reason: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line
+            .get(..offset)
+            .map(|prefix| prefix.chars().count() + 1)
+            .unwrap_or(1);
+
+        Self {
+            day,
+            line: line_idx + 1,
+            column,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Shifts the error down by `lines`, for parsers that are handed a
+    /// section of the input rather than the whole thing.
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Builds an error covering the whole of a line.
+    pub fn line(day: u8, line_idx: usize, line: &str, reason: impl Into<String>) -> Self {
+        let trimmed = line.trim();
+
+        Self::at(day, line_idx, line, trimmed, reason)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} input, line {}, column {}: {} (found `{}`)",
+            self.day, self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Iterates over the lines of an input alongside their zero-based index,
+/// skipping lines that are blank.
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of_subslice() {
+        let line = "  move x from 1 to 2";
+        let token = &line[7..8];
+        let err = ParseError::at(5, 2, line, token, "expected a number");
+
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, 8);
+        assert_eq!(err.text, "x");
+        assert_eq!(
+            err.to_string(),
+            "day 5 input, line 3, column 8: expected a number (found `x`)"
+        );
+    }
+
+    #[test]
+    fn test_whole_line_skips_indentation() {
+        let err = ParseError::line(2, 0, "    A Q", "bad round");
+
+        assert_eq!(err.column, 5);
+        assert_eq!(err.text, "A Q");
+    }
+
+    #[test]
+    fn test_non_blank_lines_keep_original_numbers() {
+        let lines: Vec<(usize, &str)> = non_blank_lines("a\n\n  \nb\n").collect();
+
+        assert_eq!(lines, vec![(0, "a"), (3, "b")]);
+    }
+}
@@ -0,0 +1,270 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{non_blank_lines, ParseError};
+
+/// A position in a grid as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+    /// The `(x, y)` change from taking one step this way.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Down => (0, 1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+}
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, or `None` if they differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid with one character per cell and one row per non-blank
+    /// line. `cell_name` describes a cell in error messages, e.g. "tree".
+    pub fn parse(
+        day: u8,
+        input: &str,
+        cell_name: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut rows = Vec::new();
+
+        for (idx, line) in non_blank_lines(input) {
+            let trimmed = line.trim();
+            let row = trimmed
+                .char_indices()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| {
+                        let text = &trimmed[i..i + c.len_utf8()];
+                        ParseError::at(day, idx, line, text, format!("expected a {cell_name}"))
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+
+            match width {
+                Some(width) if width != row.len() => {
+                    return Err(ParseError::line(
+                        day,
+                        idx,
+                        line,
+                        format!("expected a row of {width} {cell_name}s"),
+                    ))
+                }
+                _ => width = Some(row.len()),
+            }
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseError::at(
+                day,
+                0,
+                input,
+                input,
+                format!("expected a grid of {cell_name}s"),
+            ));
+        }
+
+        Ok(Self::from_rows(rows).expect("rows were checked to be the same length"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every position alongside its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |y| &self[(x, y)])
+    }
+
+    /// The position one step from `pos` in `direction`, if it's in the grid.
+    pub fn step(&self, (x, y): Pos, direction: Direction) -> Option<Pos> {
+        let (dx, dy) = direction.delta();
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.get(next).map(|_| next)
+    }
+
+    /// The positions from `pos` (not included) to the edge of the grid in
+    /// `direction`, nearest first.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(Some(pos), move |&pos| self.step(pos, direction)).skip(1)
+    }
+
+    /// The up to four positions next to `pos`.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid with one character per cell and one line per row.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid."))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse(0, "abc\ndef\n", "letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.render(|c| c.to_ascii_uppercase()), "ABC\nDEF");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let digit = |c: char| c.to_digit(10);
+
+        let err = Grid::parse(8, "12\n1x\n", "digit", digit).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.reason, "expected a digit");
+
+        let err = Grid::parse(8, "12\n123\n", "digit", digit).unwrap_err();
+        assert_eq!(err.reason, "expected a row of 2 digits");
+
+        assert!(Grid::parse(8, "\n", "digit", digit).is_err());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![2, 3]]), None);
+    }
+
+    #[test]
+    fn test_rays_and_neighbours() {
+        let grid = example();
+
+        assert_eq!(
+            grid.ray((0, 1), Direction::Right).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1)]
+        );
+        assert_eq!(grid.ray((0, 1), Direction::Left).count(), 0);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside a 3x2 grid.")]
+    fn test_index_is_bounds_checked() {
+        let _ = example()[(3, 0)];
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory of `dayN.txt` puzzle inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where inputs are read from when nothing else is configured. This is
+/// absolute so the binaries work from any working directory.
+pub const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/data");
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// A single file, used as-is for whichever day is run.
+    File(PathBuf),
+    Stdin,
+    /// A directory holding one `dayN.txt` file per day.
+    Dir(PathBuf),
+}
+
+impl InputSource {
+    /// Picks the input source in order of precedence: an explicit input
+    /// (a path, or `-` for stdin), an explicit directory, the
+    /// `AOC_INPUT_DIR` environment variable and finally the default
+    /// directory.
+    pub fn resolve(input: Option<&str>, input_dir: Option<&str>) -> Self {
+        let env_dir = std::env::var(INPUT_DIR_VAR).ok();
+
+        Self::resolve_with_env(input, input_dir, env_dir.as_deref())
+    }
+
+    fn resolve_with_env(
+        input: Option<&str>,
+        input_dir: Option<&str>,
+        env_dir: Option<&str>,
+    ) -> Self {
+        match (input, input_dir, env_dir) {
+            (Some("-"), _, _) => Self::Stdin,
+            (Some(path), _, _) => Self::File(PathBuf::from(path)),
+            (None, Some(dir), _) => Self::Dir(PathBuf::from(dir)),
+            (None, None, Some(dir)) if !dir.is_empty() => Self::Dir(PathBuf::from(dir)),
+            _ => Self::Dir(PathBuf::from(DEFAULT_INPUT_DIR)),
+        }
+    }
+
+    /// Whether the same input would be handed to every day. Such sources
+    /// only make sense when running a single day.
+    pub fn is_single_input(&self) -> bool {
+        !matches!(self, Self::Dir(_))
+    }
+
+    pub fn path_for_day(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::File(path) => Some(path.clone()),
+            Self::Stdin => None,
+            Self::Dir(dir) => Some(dir.join(format!("day{day}.txt"))),
+        }
+    }
+
+    /// A name for the input handed to `day`, which known answers are keyed
+    /// by: its path relative to the bundled input directory for the bundled
+    /// inputs (`day7.txt`), the path as given for any other file, or
+    /// `stdin`. Naming other files by their whole path keeps a `day7.txt`
+    /// from some other directory from being checked against our answers.
+    pub fn input_name(&self, day: u8) -> String {
+        let Some(path) = self.path_for_day(day) else {
+            return "stdin".to_string();
+        };
+
+        let bundled = Path::new(DEFAULT_INPUT_DIR).canonicalize();
+        let relative = match (path.canonicalize(), bundled) {
+            (Ok(path), Ok(bundled)) => path.strip_prefix(bundled).ok().map(Path::to_path_buf),
+            _ => None,
+        };
+
+        relative.unwrap_or(path).to_string_lossy().into_owned()
+    }
+
+    pub fn load(&self, day: u8) -> io::Result<String> {
+        match self.path_for_day(day) {
+            Some(path) => std::fs::read_to_string(&path)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", path.display()))),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Dir(dir) => write!(f, "{}", dir.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() {
+        let resolve = InputSource::resolve_with_env;
+
+        assert_eq!(
+            resolve(Some("-"), Some("dir"), Some("env")),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            resolve(Some("mine.txt"), Some("dir"), Some("env")),
+            InputSource::File(PathBuf::from("mine.txt"))
+        );
+        assert_eq!(
+            resolve(None, Some("dir"), Some("env")),
+            InputSource::Dir(PathBuf::from("dir"))
+        );
+        assert_eq!(
+            resolve(None, None, Some("env")),
+            InputSource::Dir(PathBuf::from("env"))
+        );
+        assert_eq!(
+            resolve(None, None, None),
+            InputSource::Dir(PathBuf::from(DEFAULT_INPUT_DIR))
+        );
+    }
+
+    #[test]
+    fn test_directory_paths() {
+        let source = InputSource::Dir(PathBuf::from("inputs"));
+
+        assert_eq!(
+            source.path_for_day(7),
+            Some(PathBuf::from("inputs/day7.txt"))
+        );
+        assert_eq!(source.input_name(7), "inputs/day7.txt");
+        assert_eq!(InputSource::Stdin.input_name(7), "stdin");
+    }
+
+    #[test]
+    fn test_bundled_inputs_are_named_relative_to_their_dir() {
+        let bundled = InputSource::Dir(PathBuf::from(DEFAULT_INPUT_DIR));
+        let relative = InputSource::File(PathBuf::from("src/../src/data/day10_alt.txt"));
+
+        assert_eq!(bundled.input_name(4), "day4.txt");
+        assert_eq!(relative.input_name(10), "day10_alt.txt");
+
+        let elsewhere = std::env::temp_dir().join("aoc_input_name_test");
+        std::fs::create_dir_all(&elsewhere).unwrap();
+        std::fs::write(elsewhere.join("day4.txt"), "1-2,3-4\n").unwrap();
+        let other = InputSource::Dir(elsewhere.clone());
+
+        assert_eq!(
+            other.input_name(4),
+            elsewhere.join("day4.txt").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_default_dir_has_every_day() {
+        let source = InputSource::Dir(PathBuf::from(DEFAULT_INPUT_DIR));
+
+        (1..=10).for_each(|day| {
+            assert!(source.load(day).is_ok(), "Missing input for day {day}");
+        });
+    }
+
+    #[test]
+    fn test_missing_file_names_the_path() {
+        let source = InputSource::File(PathBuf::from("no/such/input.txt"));
+        let err = source.load(1).unwrap_err();
+
+        assert!(err.to_string().starts_with("no/such/input.txt"));
+    }
+}
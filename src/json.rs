@@ -0,0 +1,29 @@
+/// Renders `s` as a quoted JSON string.
+pub fn string(s: &str) -> String {
+    let mut result = String::from('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_escapes() {
+        assert_eq!(string("a\"b\\c\n#.\u{1}"), "\"a\\\"b\\\\c\\n#.\\u0001\"");
+    }
+}
@@ -0,0 +1,23 @@
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod day1;
+pub mod day10;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod runner;
+pub mod solution;
+#[cfg(test)]
+mod testing;
+pub mod verify;
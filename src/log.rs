@@ -0,0 +1,228 @@
+use std::fmt;
+use std::sync::RwLock;
+
+/// Environment variable holding a filter spec, used when no verbosity flags
+/// are given.
+pub const LOG_VAR: &str = "RUST_LOG";
+
+const CRATE_NAME: &str = env!("CARGO_CRATE_NAME");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Some(Self::Error),
+            "warn" => Some(Self::Warn),
+            "info" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            "trace" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Which messages get written: the most detailed level shown for every day
+/// (`None` for nothing at all), optionally overridden for single days.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    default: Option<Level>,
+    days: Vec<(u8, Option<Level>)>,
+}
+
+impl Filter {
+    pub const fn new(default: Option<Level>) -> Self {
+        Self {
+            default,
+            days: Vec::new(),
+        }
+    }
+
+    /// The filter for `-v` repeated `count` times: warnings by default,
+    /// then debug, then trace.
+    pub fn verbosity(count: usize) -> Self {
+        match count {
+            0 => Self::new(Some(Level::Warn)),
+            1 => Self::new(Some(Level::Debug)),
+            _ => Self::new(Some(Level::Trace)),
+        }
+    }
+
+    /// Parses a `RUST_LOG` style spec: comma separated items that are either
+    /// a level (or `off`) applying to every day, or `dayN=level` for one day,
+    /// e.g. `warn,day7=trace`. This crate's name can stand in for every day
+    /// (`aoc_2022=debug`) and prefix a day (`aoc_2022::day7=trace`). Items
+    /// for any other target, like `hyper=warn`, are meant for other programs
+    /// sharing the variable and are ignored.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Self::default();
+        let level = |s: &str| match s.trim() {
+            "off" => Ok(None),
+            s => Level::parse(s)
+                .map(Some)
+                .ok_or_else(|| format!("{s} is not a log level.")),
+        };
+
+        for item in spec.split(',').filter(|item| !item.trim().is_empty()) {
+            let (target, spec) = match item.split_once('=') {
+                Some((target, spec)) => (target.trim(), spec),
+                // A bare word that isn't a level names a target to log at
+                // every level.
+                None if Level::parse(item.trim()).is_none() && item.trim() != "off" => {
+                    (item.trim(), "trace")
+                }
+                None => {
+                    filter.default = level(item)?;
+                    continue;
+                }
+            };
+
+            let target = match target.strip_prefix(CRATE_NAME) {
+                Some("") => "",
+                Some(rest) => rest.strip_prefix("::").unwrap_or(target),
+                None => target,
+            };
+            if target.is_empty() {
+                filter.default = level(spec)?;
+            } else if let Some(day) = target.strip_prefix("day").and_then(|day| day.parse().ok()) {
+                filter.days.push((day, level(spec)?));
+            }
+        }
+
+        Ok(filter)
+    }
+
+    pub fn enabled(&self, day: u8, level: Level) -> bool {
+        let max = self
+            .days
+            .iter()
+            .rev()
+            .find(|(d, _)| *d == day)
+            .map_or(self.default, |(_, max)| *max);
+
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::verbosity(0)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Some(Level::Warn)));
+
+pub fn set_filter(filter: Filter) {
+    *FILTER.write().unwrap() = filter;
+}
+
+/// Installs the filter chosen on the command line, falling back to
+/// `RUST_LOG` and then to showing only warnings and errors.
+pub fn init(flags: Option<Filter>) -> Result<(), String> {
+    let filter = match (flags, std::env::var(LOG_VAR)) {
+        (Some(filter), _) => filter,
+        (None, Ok(spec)) => Filter::parse(&spec).map_err(|err| format!("{LOG_VAR}: {err}"))?,
+        (None, Err(_)) => Filter::default(),
+    };
+
+    set_filter(filter);
+    Ok(())
+}
+
+pub fn enabled(day: u8, level: Level) -> bool {
+    FILTER.read().unwrap().enabled(day, level)
+}
+
+/// Writes a message to stderr, so it never mixes with the answers. Use the
+/// `debug!` and `trace!` macros rather than calling this directly.
+#[doc(hidden)]
+pub fn write(day: u8, level: Level, args: fmt::Arguments) {
+    if enabled(day, level) {
+        eprintln!("[{level} day{day}] {args}");
+    }
+}
+
+/// Logs a message about a day's progress, shown with `-v`.
+#[macro_export]
+macro_rules! debug {
+    ($day:expr, $($arg:tt)+) => {
+        $crate::log::write($day, $crate::log::Level::Debug, format_args!($($arg)+))
+    };
+}
+
+/// Logs a message about every step of a day, shown with `-vv`.
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $($arg:tt)+) => {
+        $crate::log::write($day, $crate::log::Level::Trace, format_args!($($arg)+))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity() {
+        assert!(Filter::default().enabled(3, Level::Warn));
+        assert!(!Filter::default().enabled(3, Level::Debug));
+        assert!(Filter::verbosity(1).enabled(3, Level::Debug));
+        assert!(!Filter::verbosity(1).enabled(3, Level::Trace));
+        assert!(Filter::verbosity(2).enabled(3, Level::Trace));
+        assert!(!Filter::new(None).enabled(3, Level::Error));
+    }
+
+    #[test]
+    fn test_per_day_spec() {
+        let filter = Filter::parse("off,day7=trace,day9=debug").unwrap();
+
+        assert!(filter.enabled(7, Level::Trace));
+        assert!(filter.enabled(9, Level::Debug));
+        assert!(!filter.enabled(9, Level::Trace));
+        assert!(!filter.enabled(2, Level::Error));
+        assert_eq!(Filter::parse("DEBUG"), Ok(Filter::verbosity(1)));
+    }
+
+    #[test]
+    fn test_crate_targets() {
+        let filter = Filter::parse("aoc_2022=debug,aoc_2022::day7=trace").unwrap();
+
+        assert!(filter.enabled(3, Level::Debug));
+        assert!(!filter.enabled(3, Level::Trace));
+        assert!(filter.enabled(7, Level::Trace));
+    }
+
+    #[test]
+    fn test_other_targets_are_ignored() {
+        let filter = Filter::parse("hyper=warn,debug,tokio::net=trace,loud,week7=off").unwrap();
+
+        assert_eq!(filter, Filter::verbosity(1));
+        assert_eq!(Filter::parse("aoc_2022x=trace"), Ok(Filter::default()));
+    }
+
+    #[test]
+    fn test_bad_spec() {
+        assert!(Filter::parse("day7=loud").is_err());
+        assert!(Filter::parse("aoc_2022=loud").is_err());
+    }
+}
@@ -1,5 +1,162 @@
-mod day1;
+mod cli;
+
+use std::path::Path;
+
+use aoc_2022::answers::{Answers, DEFAULT_ANSWERS_FILE};
+use aoc_2022::bench;
+use aoc_2022::input::InputSource;
+use aoc_2022::runner::{self, Day};
+use aoc_2022::verify;
+use cli::{Command, DaySelection, Format};
 
 fn main() {
-    day1::solve().unwrap();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let (filter, command) = match cli::split_log_flags(&args)
+        .and_then(|(filter, args)| Ok((filter, cli::parse_args(&args)?)))
+    {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{err}\n\n{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(err) = aoc_2022::log::init(filter) {
+        eprintln!("{err}");
+        std::process::exit(2);
+    }
+
+    let registry = runner::registry();
+
+    match command {
+        Command::List => {
+            for day in &registry {
+                println!("Day {:>2}", day.number);
+            }
+        }
+        Command::Run {
+            days,
+            part,
+            format,
+            input,
+            input_dir,
+        } => {
+            let source = input_source(&days, input, input_dir);
+            let results: Vec<runner::RunResult> = selected_days(&registry, &days)
+                .into_iter()
+                .flat_map(|day| runner::run_day(day, part, &source))
+                .collect();
+
+            match format {
+                Format::Table => print!("{}", runner::format_table(&results)),
+                Format::Json => print!("{}", runner::format_json(&results)),
+            }
+
+            if !results.iter().all(|result| result.outcome.is_solved()) {
+                std::process::exit(1);
+            }
+        }
+        Command::Verify {
+            days,
+            input,
+            input_dir,
+            answers,
+        } => {
+            let source = input_source(&days, input, input_dir);
+            let answers_file = answers.unwrap_or_else(|| DEFAULT_ANSWERS_FILE.to_string());
+            let answers = Answers::load(Path::new(&answers_file)).unwrap_or_else(|err| {
+                eprintln!("{err}");
+                std::process::exit(2);
+            });
+
+            let verifications: Vec<verify::Verification> = selected_days(&registry, &days)
+                .into_iter()
+                .flat_map(|day| verify::verify_day(day, &source, &answers))
+                .collect();
+
+            print!("{}", verify::format_report(&verifications));
+
+            if verifications.iter().any(|v| v.status.is_failure()) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            input,
+            input_dir,
+            options,
+            json,
+        } => {
+            let source = input_source(&days, input, input_dir);
+            let results: Vec<bench::BenchResult> = selected_days(&registry, &days)
+                .into_iter()
+                .map(|day| bench::bench_day(day, &source, &options))
+                .collect();
+
+            print!("{}", bench::format_table(&results));
+
+            if let Some(path) = json {
+                if let Err(err) = std::fs::write(&path, bench::to_json(&results, &options)) {
+                    eprintln!("Could not write {path}: {err}");
+                    std::process::exit(2);
+                }
+            }
+
+            if results.iter().any(|result| result.timings.is_err()) {
+                std::process::exit(1);
+            }
+        }
+        Command::Explain {
+            days,
+            input,
+            input_dir,
+        } => {
+            let source = input_source(&days, input, input_dir);
+            let mut failed = false;
+
+            for day in selected_days(&registry, &days) {
+                match runner::explain_day(day, &source) {
+                    Ok(Some(text)) => println!("Day {}\n\n{text}", day.number),
+                    Ok(None) => println!("Day {} has nothing to explain.\n", day.number),
+                    Err(err) => {
+                        println!("Day {} {err}\n", day.number);
+                        failed = true;
+                    }
+                }
+            }
+
+            if failed {
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn input_source(
+    days: &DaySelection,
+    input: Option<String>,
+    input_dir: Option<String>,
+) -> InputSource {
+    let source = InputSource::resolve(input.as_deref(), input_dir.as_deref());
+    if source.is_single_input() && !days.is_single_day() {
+        eprintln!("--input can only be used when running a single day.");
+        std::process::exit(2);
+    }
+
+    source
+}
+
+fn selected_days<'a>(registry: &'a [Day], days: &DaySelection) -> Vec<&'a Day> {
+    let selected: Vec<&Day> = registry
+        .iter()
+        .filter(|day| days.includes(day.number))
+        .collect();
+
+    if selected.is_empty() {
+        eprintln!("No registered days matched the selection.");
+        std::process::exit(1);
+    }
+
+    selected
 }
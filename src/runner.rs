@@ -0,0 +1,355 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::bench::{bench_solution, BenchOptions, Timing};
+use crate::day1::Day1;
+use crate::day10::Day10;
+use crate::day2::Day2;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use crate::day7::Day7;
+use crate::day8::Day8;
+use crate::day9::Day9;
+use crate::error::ParseError;
+use crate::input::InputSource;
+use crate::json;
+use crate::solution::{explain, solve, PartAnswer, Solution};
+
+type SolveFn = fn(&str, &[usize]) -> Result<Vec<(PartAnswer, Duration)>, ParseError>;
+type BenchFn = fn(&str, &BenchOptions) -> Result<Vec<Timing>, ParseError>;
+type ExplainFn = fn(&str) -> Result<Option<String>, ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+    pub explain: ExplainFn,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            bench: bench_solution::<S>,
+            explain: explain::<S>,
+        }
+    }
+}
+
+pub fn registry() -> Vec<Day> {
+    vec![
+        Day::of::<Day1>(),
+        Day::of::<Day2>(),
+        Day::of::<Day3>(),
+        Day::of::<Day4>(),
+        Day::of::<Day5>(),
+        Day::of::<Day6>(),
+        Day::of::<Day7>(),
+        Day::of::<Day8>(),
+        Day::of::<Day9>(),
+        Day::of::<Day10>(),
+    ]
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Solved(Answer),
+    InputError(String),
+    ParseError(ParseError),
+    /// The input parsed but the part has no answer for it.
+    SolveError(String),
+}
+
+impl Outcome {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Self::Solved(_))
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Solved(answer) => write!(f, "{answer}"),
+            Self::InputError(err) => write!(f, "(could not read input: {err})"),
+            Self::ParseError(err) => write!(f, "(invalid input: {err})"),
+            Self::SolveError(err) => write!(f, "(no answer: {err})"),
+        }
+    }
+}
+
+pub struct RunResult {
+    pub day: u8,
+    pub part: usize,
+    /// The name of the input the part was run on.
+    pub input: String,
+    /// How long the answer took, including parsing. `None` if the part
+    /// couldn't be solved.
+    pub elapsed: Option<Duration>,
+    pub outcome: Outcome,
+}
+
+/// Runs every requested part of the given day. `part` is 1 or 2, or `None`
+/// to run both.
+pub fn run_day(day: &Day, part: Option<usize>, source: &InputSource) -> Vec<RunResult> {
+    let parts: Vec<usize> = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let outcomes: Vec<(Outcome, Option<Duration>)> = match source.load(day.number) {
+        Ok(input) => match (day.solve)(&input, &parts) {
+            Ok(answers) => answers
+                .into_iter()
+                .map(|(answer, elapsed)| match answer {
+                    Ok(answer) => (Outcome::Solved(answer), Some(elapsed)),
+                    Err(err) => (Outcome::SolveError(err), None),
+                })
+                .collect(),
+            Err(err) => parts
+                .iter()
+                .map(|_| (Outcome::ParseError(err.clone()), None))
+                .collect(),
+        },
+        Err(err) => parts
+            .iter()
+            .map(|_| (Outcome::InputError(err.to_string()), None))
+            .collect(),
+    };
+
+    let input = source.input_name(day.number);
+    parts
+        .into_iter()
+        .zip(outcomes)
+        .map(|(part, (outcome, elapsed))| RunResult {
+            day: day.number,
+            part,
+            input: input.clone(),
+            elapsed,
+            outcome,
+        })
+        .collect()
+}
+
+/// Explains the day's input, or gives `None` for days with nothing to
+/// explain. Input and parse errors read the same as they do in `run`.
+pub fn explain_day(day: &Day, source: &InputSource) -> Result<Option<String>, String> {
+    let input = source
+        .load(day.number)
+        .map_err(|err| Outcome::InputError(err.to_string()).to_string())?;
+
+    (day.explain)(&input).map_err(|err| Outcome::ParseError(err).to_string())
+}
+
+/// Runs both parts of a single registered day and prints the table, exiting
+/// with a failure status if the input could not be read or parsed. This is
+/// all the per-day binaries do. An optional first argument names the input
+/// file, or `-` to read it from stdin. Logging is configured by `RUST_LOG`.
+pub fn print_day(number: u8) {
+    if let Err(err) = crate::log::init(None) {
+        eprintln!("{err}");
+        std::process::exit(2);
+    }
+
+    let registry = registry();
+    let day = registry
+        .iter()
+        .find(|day| day.number == number)
+        .unwrap_or_else(|| panic!("Day {number} is not registered."));
+
+    let input = std::env::args().nth(1);
+    let source = InputSource::resolve(input.as_deref(), None);
+
+    let results = run_day(day, None, &source);
+    print!("{}", format_table(&results));
+
+    if !results.iter().all(|result| result.outcome.is_solved()) {
+        std::process::exit(1);
+    }
+}
+
+/// Lays out results as a table. Multi-line answers (like the day 10 CRT)
+/// continue on the following lines, aligned with the answer column.
+pub fn format_table(results: &[RunResult]) -> String {
+    const HEADER: [&str; 3] = ["Day", "Part", "Answer"];
+    let day_width = HEADER[0].len();
+    let part_width = HEADER[1].len();
+    let indent = " ".repeat(day_width + part_width + 4);
+
+    let mut table = format!(
+        "{:>day_width$}  {:>part_width$}  {}\n",
+        HEADER[0], HEADER[1], HEADER[2]
+    );
+    for result in results {
+        let answer = result.outcome.to_string();
+        let mut lines = answer.lines();
+        let first = lines.next().unwrap_or_default();
+
+        table += &format!(
+            "{:>day_width$}  {:>part_width$}  {first}\n",
+            result.day, result.part
+        );
+        for line in lines {
+            table += &format!("{indent}{line}\n");
+        }
+    }
+
+    table
+}
+
+/// Renders results as a JSON array with one record per part. Answers keep
+/// their type: integers are numbers, text is a string and grids are an array
+/// of rows. `elapsed` is in seconds. Parts that couldn't be solved have an
+/// `error` instead of an answer.
+pub fn format_json(results: &[RunResult]) -> String {
+    let records: Vec<String> = results
+        .iter()
+        .map(|result| {
+            let value = match &result.outcome {
+                Outcome::Solved(answer) => format!("\"answer\": {}", answer.to_json()),
+                error => format!("\"error\": {}", json::string(&error.to_string())),
+            };
+            let elapsed = result.elapsed.map_or("null".to_string(), |elapsed| {
+                elapsed.as_secs_f64().to_string()
+            });
+
+            format!(
+                "  {{\"day\": {}, \"part\": {}, {value}, \"input\": {}, \"elapsed\": {elapsed}}}",
+                result.day,
+                result.part,
+                json::string(&result.input)
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example_day, example_source};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_registry_has_every_day_in_order() {
+        let numbers: Vec<u8> = registry().iter().map(|day| day.number).collect();
+
+        assert_eq!(numbers, (1..=10).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_run_both_parts() {
+        let results = run_day(&example_day(), None, &example_source());
+        let outcomes: Vec<&Outcome> = results.iter().map(|r| &r.outcome).collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                &Outcome::Solved(Answer::Text("1: 84".to_string())),
+                &Outcome::Solved(Answer::Text("2: 84".to_string()))
+            ]
+        );
+        assert_eq!(results[0].input, "day10_alt.txt");
+        assert!(results.iter().all(|r| r.elapsed.is_some()));
+    }
+
+    #[test]
+    fn test_missing_input_file() {
+        let source = InputSource::File(PathBuf::from("src/data/no_such_day.txt"));
+        let results = run_day(&example_day(), Some(2), &source);
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0].outcome, Outcome::InputError(_)));
+    }
+
+    #[test]
+    fn test_explain_day() {
+        let mut day = example_day();
+        assert_eq!(explain_day(&day, &example_source()), Ok(None));
+
+        day.explain = |input| Ok(Some(format!("{} bytes", input.len())));
+        assert_eq!(
+            explain_day(&day, &example_source()),
+            Ok(Some("84 bytes".to_string()))
+        );
+
+        let source = InputSource::File(PathBuf::from("src/data/no_such_day.txt"));
+        assert!(explain_day(&day, &source)
+            .unwrap_err()
+            .starts_with("(could not read input"));
+    }
+
+    fn example_results() -> Vec<RunResult> {
+        vec![
+            RunResult {
+                day: 9,
+                part: 1,
+                input: "day9.txt".to_string(),
+                elapsed: Some(Duration::from_millis(250)),
+                outcome: Outcome::Solved(Answer::Integer(13)),
+            },
+            RunResult {
+                day: 10,
+                part: 2,
+                input: "day10.txt".to_string(),
+                elapsed: Some(Duration::from_micros(1500)),
+                outcome: Outcome::Solved(Answer::grid("#.#\n.#.")),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_table_aligns_multiline_answers() {
+        let results = example_results();
+
+        let expected = "\
+Day  Part  Answer
+  9     1  13
+ 10     2  #.#
+           .#.
+";
+
+        assert_eq!(format_table(&results), expected);
+    }
+
+    #[test]
+    fn test_json_records() {
+        let mut results = example_results();
+        results.push(RunResult {
+            day: 5,
+            part: 1,
+            input: "stdin".to_string(),
+            elapsed: None,
+            outcome: Outcome::InputError("gone".to_string()),
+        });
+
+        let expected = r##"[
+  {"day": 9, "part": 1, "answer": 13, "input": "day9.txt", "elapsed": 0.25},
+  {"day": 10, "part": 2, "answer": ["#.#", ".#."], "input": "day10.txt", "elapsed": 0.0015},
+  {"day": 5, "part": 1, "error": "(could not read input: gone)", "input": "stdin", "elapsed": null}
+]
+"##;
+
+        assert_eq!(format_json(&results), expected);
+    }
+
+    #[test]
+    fn test_parse_error_fails_every_part() {
+        let day = Day {
+            number: 4,
+            solve: |input, _| Err(ParseError::at(4, 0, input, input, "nope")),
+            bench: |_, _| Ok(Vec::new()),
+            explain: |_| Ok(None),
+        };
+        let results = run_day(&day, None, &example_source());
+
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|r| matches!(r.outcome, Outcome::ParseError(_))));
+    }
+}
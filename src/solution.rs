@@ -0,0 +1,119 @@
+use std::time::{Duration, Instant};
+
+use crate::answer::{Answer, IntoAnswer};
+use crate::error::ParseError;
+
+/// A part's answer, or why it couldn't give one.
+pub type PartAnswer = Result<Answer, String>;
+
+/// A single day's puzzle. The input is parsed once and both parts are
+/// solved from the parsed form.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type PartOne: IntoAnswer;
+    type PartTwo: IntoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// A human readable account of how the answers come out of the input,
+    /// for days that have one.
+    fn explain(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Parses the input and solves each requested part (1 or 2), converting the
+/// answers to `Answer` so days with different answer types can sit side by
+/// side. Each answer, or the reason a part couldn't give one, comes with how
+/// long it took, parsing included.
+pub fn solve<S: Solution>(
+    input: &str,
+    parts: &[usize],
+) -> Result<Vec<(PartAnswer, Duration)>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part_one(&parsed).into_answer(),
+                2 => S::part_two(&parsed).into_answer(),
+                _ => panic!("There is no part {part}."),
+            };
+
+            (answer, parse_time + start.elapsed())
+        })
+        .collect();
+
+    Ok(answers)
+}
+
+/// Parses the input and explains it, if the day can.
+pub fn explain<S: Solution>(input: &str) -> Result<Option<String>, ParseError> {
+    Ok(S::explain(&S::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        const DAY: u8 = 0;
+
+        type Input = Vec<String>;
+        type PartOne = usize;
+        type PartTwo = Result<String, String>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(String::from).collect())
+        }
+
+        fn part_one(input: &Self::Input) -> Self::PartOne {
+            input.len()
+        }
+
+        fn part_two(input: &Self::Input) -> Self::PartTwo {
+            match input.is_empty() {
+                true => Err("no lines".to_string()),
+                false => Ok(input.concat()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_requested_parts() {
+        let actual: Vec<PartAnswer> = solve::<LineCount>("ab\ncd", &[2, 1])
+            .unwrap()
+            .into_iter()
+            .map(|(answer, _)| answer)
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![Ok(Answer::Text("abcd".to_string())), Ok(Answer::Integer(2))]
+        );
+    }
+
+    #[test]
+    fn test_failed_part_keeps_its_reason() {
+        let actual: Vec<PartAnswer> = solve::<LineCount>("", &[1, 2])
+            .unwrap()
+            .into_iter()
+            .map(|(answer, _)| answer)
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![Ok(Answer::Integer(0)), Err("no lines".to_string())]
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! Fixtures shared by the runner and verify tests.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::input::{InputSource, DEFAULT_INPUT_DIR};
+use crate::runner::Day;
+
+/// A day whose answer to each part is the part number and the length of
+/// its input, e.g. `1: 84`.
+pub fn example_day() -> Day {
+    Day {
+        number: 10,
+        solve: |input, parts| {
+            Ok(parts
+                .iter()
+                .map(|part| {
+                    let answer = Ok(Answer::Text(format!("{part}: {}", input.len())));
+                    (answer, Duration::ZERO)
+                })
+                .collect())
+        },
+        bench: |_, _| Ok(Vec::new()),
+        explain: |_| Ok(None),
+    }
+}
+
+/// A bundled 84 byte input.
+pub fn example_source() -> InputSource {
+    InputSource::File(Path::new(DEFAULT_INPUT_DIR).join("day10_alt.txt"))
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use crate::answers::Answers;
+use crate::input::InputSource;
+use crate::runner::{run_day, Day, Outcome};
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The part ran, but there is no recorded answer to compare it with.
+    Missing {
+        actual: String,
+    },
+    /// The part could not run at all.
+    Error(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Fail { .. } | Self::Error(_))
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail { .. } => "FAIL",
+            Self::Missing { .. } => "missing",
+            Self::Error(_) => "ERROR",
+        }
+    }
+}
+
+pub struct Verification {
+    pub day: u8,
+    pub part: usize,
+    pub input: String,
+    pub status: Status,
+}
+
+/// Runs both parts of a day and checks them against the known answers for
+/// the input it was given.
+pub fn verify_day(day: &Day, source: &InputSource, answers: &Answers) -> Vec<Verification> {
+    let input = source.input_name(day.number);
+
+    run_day(day, None, source)
+        .into_iter()
+        .map(|result| {
+            let expected = answers.get(result.day, &input, result.part);
+            let status = match (result.outcome, expected) {
+                (Outcome::Solved(actual), Some(expected)) if actual.to_string() == expected => {
+                    Status::Pass
+                }
+                (Outcome::Solved(actual), Some(expected)) => Status::Fail {
+                    expected: expected.to_string(),
+                    actual: actual.to_string(),
+                },
+                (Outcome::Solved(actual), None) => Status::Missing {
+                    actual: actual.to_string(),
+                },
+                (outcome, _) => Status::Error(outcome.to_string()),
+            };
+
+            Verification {
+                day: result.day,
+                part: result.part,
+                input: input.clone(),
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Line by line diff of a wrong answer: matching lines are indented and
+/// differing ones are shown as `-expected` / `+actual`.
+fn diff(expected: &str, actual: &str) -> Vec<String> {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut result = Vec::new();
+
+    for idx in 0..expected.len().max(actual.len()) {
+        match (expected.get(idx), actual.get(idx)) {
+            (Some(e), Some(a)) if e == a => result.push(format!("  {e}")),
+            (e, a) => {
+                if let Some(e) = e {
+                    result.push(format!("- {e}"));
+                }
+                if let Some(a) = a {
+                    result.push(format!("+ {a}"));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = self.status.label();
+        write!(
+            f,
+            "{:>3}  {:>4}  {label:<7}  {}",
+            self.day, self.part, self.input
+        )?;
+
+        match &self.status {
+            Status::Pass => Ok(()),
+            Status::Missing { actual } if !actual.contains('\n') => {
+                write!(f, "  (got {actual})")
+            }
+            Status::Missing { .. } => write!(f, "  (no answer recorded)"),
+            Status::Error(err) => write!(f, "  {err}"),
+            Status::Fail { expected, actual } => {
+                for line in diff(expected, actual) {
+                    write!(f, "\n           {line}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+pub fn format_report(verifications: &[Verification]) -> String {
+    let mut report = String::from("Day  Part  Status   Input\n");
+    for verification in verifications {
+        report += &format!("{verification}\n");
+    }
+
+    let count = |label: &str| {
+        verifications
+            .iter()
+            .filter(|v| v.status.label() == label)
+            .count()
+    };
+    report += &format!(
+        "{} passed, {} failed, {} missing, {} errors\n",
+        count("pass"),
+        count("FAIL"),
+        count("missing"),
+        count("ERROR")
+    );
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{example_day, example_source};
+
+    #[test]
+    fn test_pass_fail_and_missing() {
+        let answers =
+            Answers::parse("[day10.\"day10_alt.txt\"]\npart1 = \"1: 84\"\npart2 = \"2: 85\"")
+                .unwrap();
+        let statuses: Vec<Status> = verify_day(&example_day(), &example_source(), &answers)
+            .into_iter()
+            .map(|v| v.status)
+            .collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                Status::Pass,
+                Status::Fail {
+                    expected: "2: 85".to_string(),
+                    actual: "2: 84".to_string()
+                }
+            ]
+        );
+
+        let statuses: Vec<Status> =
+            verify_day(&example_day(), &example_source(), &Answers::default())
+                .into_iter()
+                .map(|v| v.status)
+                .collect();
+
+        assert!(matches!(statuses[0], Status::Missing { .. }));
+    }
+
+    #[test]
+    fn test_same_file_name_elsewhere_is_missing() {
+        let dir = std::env::temp_dir().join("aoc_verify_elsewhere_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day10.txt"), "noop\n").unwrap();
+        let answers = Answers::parse("[day10.\"day10.txt\"]\npart1 = \"1: 5\"").unwrap();
+
+        let verifications = verify_day(&example_day(), &InputSource::Dir(dir), &answers);
+
+        assert!(matches!(verifications[0].status, Status::Missing { .. }));
+        assert!(verifications[0]
+            .input
+            .ends_with("aoc_verify_elsewhere_test/day10.txt"));
+    }
+
+    #[test]
+    fn test_multiline_diff() {
+        let actual = diff("##..\n#..#\n....", "##..\n#.##");
+
+        assert_eq!(actual, vec!["  ##..", "- #..#", "+ #.##", "- ...."]);
+    }
+
+    #[test]
+    fn test_report() {
+        let verifications = vec![
+            Verification {
+                day: 3,
+                part: 1,
+                input: "day3.txt".to_string(),
+                status: Status::Pass,
+            },
+            Verification {
+                day: 3,
+                part: 2,
+                input: "day3.txt".to_string(),
+                status: Status::Fail {
+                    expected: "2585".to_string(),
+                    actual: "2590".to_string(),
+                },
+            },
+        ];
+
+        let expected = "\
+Day  Part  Status   Input
+  3     1  pass     day3.txt
+  3     2  FAIL     day3.txt
+           - 2585
+           + 2590
+1 passed, 1 failed, 0 missing, 0 errors
+";
+
+        assert_eq!(format_report(&verifications), expected);
+    }
+}